@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::NodeID;
+
+use leo_span::Span;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of a source comment.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CommentKind {
+    /// A comment of the form `// ...`.
+    Line,
+    /// A comment of the form `/* ... */`.
+    Block,
+}
+
+/// A comment in the source, e.g. `// note` or `/* note */`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    /// The kind of the comment.
+    pub kind: CommentKind,
+    /// The text of the comment, including its delimiters but not a trailing line break.
+    pub text: String,
+    /// The span of the comment.
+    pub span: Span,
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// The comments of a program, attached to the nearest statement or expression.
+///
+/// Comments are not part of the AST itself; they are kept in this side table, keyed by the `NodeID` of the node
+/// they belong to, so that a pretty-printer can re-emit them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Comments {
+    /// Comments on the lines directly preceding a node, or directly before it on the same line.
+    pub leading: IndexMap<NodeID, Vec<Comment>>,
+    /// Comments directly following a node on the same line.
+    pub trailing: IndexMap<NodeID, Vec<Comment>>,
+    /// Comments that are not adjacent to any statement or expression, e.g. a file header.
+    pub detached: Vec<Comment>,
+}
+
+impl Comments {
+    /// Returns the comments preceding the node with the given id.
+    pub fn leading(&self, id: NodeID) -> &[Comment] {
+        self.leading.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the comments following the node with the given id.
+    pub fn trailing(&self, id: NodeID) -> &[Comment] {
+        self.trailing.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the total number of comments.
+    pub fn len(&self) -> usize {
+        self.leading.values().chain(self.trailing.values()).map(Vec::len).sum::<usize>() + self.detached.len()
+    }
+
+    /// Returns `true` if there are no comments.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
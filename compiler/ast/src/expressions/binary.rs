@@ -24,6 +24,8 @@ use leo_span::{sym, Symbol};
 pub enum BinaryOperation {
     /// Addition, i.e. `+`, `.add()`.
     Add,
+    /// Saturating addition, i.e. `.add_saturating()`.
+    AddSaturating,
     /// Wrapping addition, i.e. `.add_wrapped()`.
     AddWrapped,
     /// Logical AND, i.e. `&&`.
@@ -44,6 +46,8 @@ pub enum BinaryOperation {
     Lte,
     /// Lesser-than relation, i.e. `<`, `.lt()`.
     Lt,
+    /// Logical XOR, i.e. `^^`.
+    LogicalXor,
     /// Arithmetic modulo, i.e. `.mod()`
     Mod,
     /// Multiplication, i.e. `*`, `.mul()`.
@@ -78,6 +82,8 @@ pub enum BinaryOperation {
     ShrWrapped,
     /// Subtraction, i.e. `-`, `.sub()`.
     Sub,
+    /// Saturating subtraction, i.e. `.sub_saturating()`.
+    SubSaturating,
     /// Wrapped subtraction, i.e. `.sub_wrapped()`.
     SubWrapped,
     /// Bitwise XOR, i.e. `.xor()`.
@@ -88,6 +94,7 @@ impl fmt::Display for BinaryOperation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match self {
             Self::Add => "+",
+            Self::AddSaturating => "add_saturating",
             Self::AddWrapped => "add_wrapped",
             Self::And => "&&",
             Self::BitwiseAnd => "&",
@@ -98,6 +105,7 @@ impl fmt::Display for BinaryOperation {
             Self::Gt => ">",
             Self::Lte => "<=",
             Self::Lt => "<",
+            Self::LogicalXor => "^^",
             Self::Mod => "mod",
             Self::Mul => "*",
             Self::MulWrapped => "mul_wrapped",
@@ -115,6 +123,7 @@ impl fmt::Display for BinaryOperation {
             Self::Shr => ">>",
             Self::ShrWrapped => "shr_wrapped",
             Self::Sub => "-",
+            Self::SubSaturating => "sub_saturating",
             Self::SubWrapped => "sub_wrapped",
             Self::Xor => "^",
         })
@@ -127,6 +136,7 @@ impl BinaryOperation {
     pub fn from_symbol(symbol: Symbol) -> Option<Self> {
         Some(match symbol {
             sym::add => Self::Add,
+            sym::add_saturating => Self::AddSaturating,
             sym::add_wrapped => Self::AddWrapped,
             sym::and => Self::BitwiseAnd,
             sym::div => Self::Div,
@@ -152,6 +162,7 @@ impl BinaryOperation {
             sym::shr => Self::Shr,
             sym::shr_wrapped => Self::ShrWrapped,
             sym::sub => Self::Sub,
+            sym::sub_saturating => Self::SubSaturating,
             sym::sub_wrapped => Self::SubWrapped,
             sym::xor => Self::Xor,
             _ => return None,
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{IntegerType, NodeBuilder};
+use leo_span::Symbol;
+
+/// A helper for constructing [`Expression`]s programmatically.
+///
+/// Every node created by the builder gets a fresh `NodeID` from the wrapped [`NodeBuilder`]
+/// and a default span, so passes and tests can assemble expressions without the `Box::new` boilerplate.
+///
+/// ```
+/// use leo_ast::{ExpressionBuilder, IntegerType, NodeBuilder};
+/// use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+///
+/// create_session_if_not_set_then(|_| {
+///     let node_builder = NodeBuilder::default();
+///     let b = ExpressionBuilder::new(&node_builder);
+///
+///     let sum = b.add(b.identifier(Symbol::intern("a")), b.integer(IntegerType::U32, 1));
+///     assert_eq!(sum.to_string(), "a + 1u32");
+///
+///     let call = b.call(Symbol::intern("foo"), vec![sum, b.boolean(true)]);
+///     assert_eq!(call.to_string(), "foo(a + 1u32, true)");
+/// });
+/// ```
+#[derive(Clone, Copy)]
+pub struct ExpressionBuilder<'a> {
+    /// The node builder used to assign IDs to the constructed nodes.
+    node_builder: &'a NodeBuilder,
+}
+
+impl<'a> ExpressionBuilder<'a> {
+    /// Returns a new expression builder drawing IDs from `node_builder`.
+    pub fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns the identifier expression `name`.
+    pub fn identifier(&self, name: Symbol) -> Expression {
+        Expression::Identifier(Identifier { name, span: Default::default(), id: self.node_builder.next_id() })
+    }
+
+    /// Returns the boolean literal `value`.
+    pub fn boolean(&self, value: bool) -> Expression {
+        Expression::Literal(Literal::Boolean(value, Default::default(), self.node_builder.next_id()))
+    }
+
+    /// Returns the integer literal `value` of type `type_`, e.g. `1u32`.
+    pub fn integer(&self, type_: IntegerType, value: impl ToString) -> Expression {
+        Expression::Literal(Literal::Integer(type_, value.to_string(), Default::default(), self.node_builder.next_id()))
+    }
+
+    /// Returns the field literal `value`, e.g. `1field`.
+    pub fn field(&self, value: impl ToString) -> Expression {
+        Expression::Literal(Literal::Field(value.to_string(), Default::default(), self.node_builder.next_id()))
+    }
+
+    /// Returns the binary expression `left op right`.
+    pub fn binary(&self, op: BinaryOperation, left: Expression, right: Expression) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the expression `left + right`.
+    pub fn add(&self, left: Expression, right: Expression) -> Expression {
+        self.binary(BinaryOperation::Add, left, right)
+    }
+
+    /// Returns the expression `left - right`.
+    pub fn sub(&self, left: Expression, right: Expression) -> Expression {
+        self.binary(BinaryOperation::Sub, left, right)
+    }
+
+    /// Returns the expression `left * right`.
+    pub fn mul(&self, left: Expression, right: Expression) -> Expression {
+        self.binary(BinaryOperation::Mul, left, right)
+    }
+
+    /// Returns the unary expression `op receiver`.
+    pub fn unary(&self, op: UnaryOperation, receiver: Expression) -> Expression {
+        Expression::Unary(UnaryExpression {
+            receiver: Box::new(receiver),
+            op,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the ternary expression `condition ? if_true : if_false`.
+    pub fn ternary(&self, condition: Expression, if_true: Expression, if_false: Expression) -> Expression {
+        Expression::Ternary(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the call expression `function(arguments)`.
+    pub fn call(&self, function: Symbol, arguments: Vec<Expression>) -> Expression {
+        Expression::Call(CallExpression {
+            function: Box::new(self.identifier(function)),
+            arguments,
+            external: None,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the array expression `[elements]`.
+    pub fn array(&self, elements: Vec<Expression>) -> Expression {
+        Expression::Array(ArrayExpression { elements, span: Default::default(), id: self.node_builder.next_id() })
+    }
+}
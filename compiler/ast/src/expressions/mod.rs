@@ -29,12 +29,18 @@ pub use array::*;
 mod binary;
 pub use binary::*;
 
+mod builder;
+pub use builder::*;
+
 mod call;
 pub use call::*;
 
 mod cast;
 pub use cast::*;
 
+mod repeat;
+pub use repeat::*;
+
 mod struct_init;
 pub use struct_init::*;
 
@@ -69,6 +75,8 @@ pub enum Expression {
     Call(CallExpression),
     /// A cast expression, e.g., `42u32 as u8`.
     Cast(CastExpression),
+    /// An array repeat expression, e.g., `[0u8; 32]`.
+    Repeat(RepeatExpression),
     /// An expression constructing a struct like `Foo { bar: 42, baz }`.
     Struct(StructExpression),
     /// An expression of type "error".
@@ -97,6 +105,7 @@ impl Node for Expression {
             Binary(n) => n.span(),
             Call(n) => n.span(),
             Cast(n) => n.span(),
+            Repeat(n) => n.span(),
             Struct(n) => n.span(),
             Err(n) => n.span(),
             Identifier(n) => n.span(),
@@ -116,6 +125,7 @@ impl Node for Expression {
             Binary(n) => n.set_span(span),
             Call(n) => n.set_span(span),
             Cast(n) => n.set_span(span),
+            Repeat(n) => n.set_span(span),
             Struct(n) => n.set_span(span),
             Identifier(n) => n.set_span(span),
             Literal(n) => n.set_span(span),
@@ -135,6 +145,7 @@ impl Node for Expression {
             Binary(n) => n.id(),
             Call(n) => n.id(),
             Cast(n) => n.id(),
+            Repeat(n) => n.id(),
             Struct(n) => n.id(),
             Identifier(n) => n.id(),
             Literal(n) => n.id(),
@@ -154,6 +165,7 @@ impl Node for Expression {
             Binary(n) => n.set_id(id),
             Call(n) => n.set_id(id),
             Cast(n) => n.set_id(id),
+            Repeat(n) => n.set_id(id),
             Struct(n) => n.set_id(id),
             Identifier(n) => n.set_id(id),
             Literal(n) => n.set_id(id),
@@ -175,6 +187,7 @@ impl fmt::Display for Expression {
             Binary(n) => n.fmt(f),
             Call(n) => n.fmt(f),
             Cast(n) => n.fmt(f),
+            Repeat(n) => n.fmt(f),
             Struct(n) => n.fmt(f),
             Err(n) => n.fmt(f),
             Identifier(n) => n.fmt(f),
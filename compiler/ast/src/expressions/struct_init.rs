@@ -44,7 +44,7 @@ impl fmt::Display for StructVariableInitializer {
     }
 }
 
-/// A struct initialization expression, e.g., `Foo { bar: 42, baz }`.
+/// A struct initialization expression, e.g., `Foo { bar: 42, baz }` or `Foo { bar: 42, ..foo }`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StructExpression {
     /// The name of the structure type to initialize.
@@ -54,6 +54,9 @@ pub struct StructExpression {
     /// N.B. Any functions or member constants in the struct definition
     /// are excluded from this list.
     pub members: Vec<StructVariableInitializer>,
+    /// The struct that fills in any members not initialized in `members`,
+    /// i.e. `p` in `Point { x: 5u32, ..p }`.
+    pub base: Option<Box<Expression>>,
     /// A span from `name` to `}`.
     pub span: Span,
     /// The ID of the node.
@@ -90,7 +93,11 @@ impl StructExpression {
 
 impl fmt::Display for StructExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{{{}}}", self.members.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", "))
+        let mut members = self.members.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        if let Some(base) = &self.base {
+            members.push(format!("..{base}"));
+        }
+        write!(f, "{{{}}}", members.join(", "))
     }
 }
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{External, Identifier, Mode, Node, NodeID, Type};
+use crate::{Expression, External, Identifier, Mode, Node, NodeID, Type};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -60,6 +60,14 @@ impl Input {
             External(_) => Mode::None,
         }
     }
+
+    pub fn default_value(&self) -> Option<&Expression> {
+        use Input::*;
+        match self {
+            Internal(input) => input.default.as_ref(),
+            External(_) => None,
+        }
+    }
 }
 
 impl Node for Input {
@@ -105,6 +113,8 @@ pub struct FunctionInput {
     pub mode: Mode,
     /// What's the parameter's type?
     pub type_: Type,
+    /// The value passed when a call omits this parameter, e.g. `1u8` in `b: u8 = 1u8`.
+    pub default: Option<Expression>,
     /// The parameters span from any annotations to its type.
     pub span: Span,
     /// The ID of the node.
@@ -113,7 +123,11 @@ pub struct FunctionInput {
 
 impl FunctionInput {
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}: {}", self.mode, self.identifier, self.type_)
+        write!(f, "{} {}: {}", self.mode, self.identifier, self.type_)?;
+        if let Some(default) = &self.default {
+            write!(f, " = {default}")?;
+        }
+        Ok(())
     }
 }
 
@@ -35,6 +35,9 @@ pub use input::*;
 pub mod output;
 pub use output::*;
 
+pub mod signature;
+pub use signature::*;
+
 pub mod mode;
 pub use mode::*;
 
@@ -111,6 +114,11 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns the signature of the function.
+    pub fn signature(&self) -> FunctionSignature {
+        FunctionSignature::from(self)
+    }
+
     /// Returns `true` if the function name is `main`.
     pub fn is_main(&self) -> bool {
         self.name() == sym::main
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Function, Identifier, Mode, Type, Variant};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The signature of a function, i.e. everything but its body.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionSignature {
+    /// Is this function a transition, inlined, or a regular function?
+    pub variant: Variant,
+    /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
+    pub identifier: Identifier,
+    /// The function's parameters, in declaration order.
+    pub parameters: Vec<ParameterSignature>,
+    /// The mode and type of each of the function's outputs, in declaration order.
+    pub outputs: Vec<(Mode, Type)>,
+}
+
+/// A single parameter in a [`FunctionSignature`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterSignature {
+    /// The name of the parameter.
+    pub identifier: Identifier,
+    /// The mode of the parameter.
+    pub mode: Mode,
+    /// The type of the parameter.
+    pub type_: Type,
+}
+
+/// A value of a transition that is revealed to the verifier.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PublicInput {
+    /// A public parameter, e.g. `public a: u32`.
+    Parameter(ParameterSignature),
+    /// A public output, given by its position among the function's outputs.
+    Output(usize, Type),
+}
+
+impl FunctionSignature {
+    /// Returns the public parameters and outputs of the function, in the order in which the verifier receives them.
+    /// That is, the public parameters in declaration order, followed by the public outputs in declaration order.
+    pub fn public_inputs(&self) -> Vec<PublicInput> {
+        let parameters = self
+            .parameters
+            .iter()
+            .filter(|parameter| parameter.mode == Mode::Public)
+            .map(|parameter| PublicInput::Parameter(parameter.clone()));
+        let outputs = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, (mode, _))| *mode == Mode::Public)
+            .map(|(index, (_, type_))| PublicInput::Output(index, type_.clone()));
+        parameters.chain(outputs).collect()
+    }
+}
+
+impl From<&Function> for FunctionSignature {
+    fn from(function: &Function) -> Self {
+        Self {
+            variant: function.variant,
+            identifier: function.identifier,
+            parameters: function
+                .input
+                .iter()
+                .map(|input| ParameterSignature {
+                    identifier: input.identifier(),
+                    mode: input.mode(),
+                    type_: input.type_(),
+                })
+                .collect(),
+            outputs: function.output.iter().map(|output| (output.mode(), output.type_())).collect(),
+        }
+    }
+}
+
+/// Formats `item` prefixed by `mode`, if any.
+fn format_with_mode(mode: Mode, item: impl fmt::Display) -> String {
+    match mode {
+        Mode::None => item.to_string(),
+        mode => format!("{mode} {item}"),
+    }
+}
+
+impl fmt::Display for ParameterSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format_with_mode(self.mode, format!("{}: {}", self.identifier, self.type_)))
+    }
+}
+
+impl fmt::Display for FunctionSignature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.variant {
+            Variant::Inline => write!(f, "inline ")?,
+            Variant::Standard => write!(f, "function ")?,
+            Variant::Transition => write!(f, "transition ")?,
+        }
+        let parameters = self.parameters.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+        let outputs = self.outputs.iter().map(|(mode, type_)| format_with_mode(*mode, type_)).collect::<Vec<_>>();
+        let returns = match outputs.len() {
+            0 => "()".to_string(),
+            1 => outputs[0].clone(),
+            _ => format!("({})", outputs.join(", ")),
+        };
+        write!(f, "{}({parameters}) -> {returns}", self.identifier)
+    }
+}
+
+impl fmt::Display for PublicInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parameter(parameter) => write!(f, "{parameter}"),
+            Self::Output(index, type_) => write!(f, "output {index}: {type_}"),
+        }
+    }
+}
@@ -30,6 +30,7 @@ pub trait ExpressionConsumer {
             Expression::Binary(binary) => self.consume_binary(binary),
             Expression::Call(call) => self.consume_call(call),
             Expression::Cast(cast) => self.consume_cast(cast),
+            Expression::Repeat(repeat) => self.consume_repeat(repeat),
             Expression::Struct(struct_) => self.consume_struct_init(struct_),
             Expression::Err(err) => self.consume_err(err),
             Expression::Identifier(identifier) => self.consume_identifier(identifier),
@@ -51,6 +52,8 @@ pub trait ExpressionConsumer {
 
     fn consume_cast(&mut self, _input: CastExpression) -> Self::Output;
 
+    fn consume_repeat(&mut self, _input: RepeatExpression) -> Self::Output;
+
     fn consume_struct_init(&mut self, _input: StructExpression) -> Self::Output;
 
     fn consume_err(&mut self, _input: ErrExpression) -> Self::Output {
@@ -31,6 +31,7 @@ pub trait ExpressionReconstructor {
             Expression::Binary(binary) => self.reconstruct_binary(binary),
             Expression::Call(call) => self.reconstruct_call(call),
             Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Repeat(repeat) => self.reconstruct_repeat(repeat),
             Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
             Expression::Err(err) => self.reconstruct_err(err),
             Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
@@ -162,6 +163,18 @@ pub trait ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_repeat(&mut self, input: RepeatExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Repeat(RepeatExpression {
+                element: Box::new(self.reconstruct_expression(*input.element).0),
+                count: input.count,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
         (
             Expression::Struct(StructExpression {
@@ -179,6 +192,7 @@ pub trait ExpressionReconstructor {
                         id: member.id,
                     })
                     .collect(),
+                base: input.base.map(|base| Box::new(self.reconstruct_expression(*base).0)),
                 span: input.span,
                 id: input.id,
             }),
@@ -21,6 +21,45 @@
 use crate::*;
 
 /// A Visitor trait for expressions in the AST.
+///
+/// By default, each method visits the subexpressions of its node, so an analysis only overrides the nodes it needs.
+/// For example, the following visitor counts the additions in an expression.
+/// ```
+/// use leo_ast::{BinaryExpression, BinaryOperation, ExpressionBuilder, ExpressionVisitor, IntegerType, NodeBuilder};
+/// use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+///
+/// #[derive(Default)]
+/// struct AddCounter {
+///     count: usize,
+/// }
+///
+/// impl<'a> ExpressionVisitor<'a> for AddCounter {
+///     type AdditionalInput = ();
+///     type Output = ();
+///
+///     fn visit_binary(&mut self, input: &'a BinaryExpression, additional: &Self::AdditionalInput) {
+///         if input.op == BinaryOperation::Add {
+///             self.count += 1;
+///         }
+///         self.visit_expression(&input.left, additional);
+///         self.visit_expression(&input.right, additional);
+///     }
+/// }
+///
+/// create_session_if_not_set_then(|_| {
+///     let node_builder = NodeBuilder::default();
+///     let b = ExpressionBuilder::new(&node_builder);
+///     let a = || b.identifier(Symbol::intern("a"));
+///
+///     // (a + a) * foo(a + 1u32, [a + a, a])
+///     let arguments = vec![b.add(a(), b.integer(IntegerType::U32, 1)), b.array(vec![b.add(a(), a()), a()])];
+///     let expression = b.mul(b.add(a(), a()), b.call(Symbol::intern("foo"), arguments));
+///
+///     let mut counter = AddCounter::default();
+///     counter.visit_expression(&expression, &());
+///     assert_eq!(counter.count, 3);
+/// });
+/// ```
 pub trait ExpressionVisitor<'a> {
     type AdditionalInput: Default;
     type Output: Default;
@@ -32,6 +71,7 @@ pub trait ExpressionVisitor<'a> {
             Expression::Binary(binary) => self.visit_binary(binary, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
@@ -91,7 +131,20 @@ pub trait ExpressionVisitor<'a> {
         Default::default()
     }
 
-    fn visit_struct_init(&mut self, _input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+    fn visit_repeat(&mut self, input: &'a RepeatExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.element, additional);
+        Default::default()
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| {
+            if let Some(expression) = &member.expression {
+                self.visit_expression(expression, additional);
+            }
+        });
+        if let Some(base) = &input.base {
+            self.visit_expression(base, additional);
+        }
         Default::default()
     }
 
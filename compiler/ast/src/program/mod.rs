@@ -22,6 +22,8 @@ pub use program_id::*;
 pub mod program_scope;
 pub use program_scope::*;
 
+use crate::FunctionSignature;
+use leo_errors::Result;
 use leo_span::{Span, Symbol};
 
 use indexmap::IndexMap;
@@ -50,6 +52,38 @@ impl fmt::Display for Program {
     }
 }
 
+impl Program {
+    /// Returns the signatures of all functions defined in the program's scopes, in declaration order.
+    /// Functions of imported programs are not included.
+    pub fn signatures(&self) -> Vec<FunctionSignature> {
+        self.program_scopes
+            .values()
+            .flat_map(|scope| scope.functions.iter().map(|(_, function)| function.signature()))
+            .collect()
+    }
+
+    /// Merges `other` into this program and returns the combined program.
+    ///
+    /// Imports are unioned, keeping this program's entry for a name imported by both.
+    /// Program scopes with different program ids are kept side by side, while scopes with the same id are merged
+    /// with [`ProgramScope::merge`], so a definition present in both is an error.
+    /// Both programs must have been parsed with the same `NodeBuilder`, so that their node ids do not overlap.
+    pub fn merge(mut self, other: Program) -> Result<Program> {
+        for (name, import) in other.imports {
+            self.imports.entry(name).or_insert(import);
+        }
+        for (name, program_scope) in other.program_scopes {
+            match self.program_scopes.get_mut(&name) {
+                Some(existing) => existing.merge(program_scope)?,
+                None => {
+                    self.program_scopes.insert(name, program_scope);
+                }
+            }
+        }
+        Ok(self)
+    }
+}
+
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
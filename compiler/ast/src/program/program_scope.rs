@@ -18,6 +18,7 @@
 
 use crate::{ConstDeclaration, Function, Mapping, ProgramId, Struct};
 
+use leo_errors::{AstError, Result};
 use leo_span::{Span, Symbol};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -54,3 +55,32 @@ impl fmt::Display for ProgramScope {
         Ok(())
     }
 }
+
+impl ProgramScope {
+    /// Appends the definitions of `other` to this program scope.
+    /// Returns an error, pointing at `other`'s definition, if a const, struct, mapping, or function is defined in both.
+    pub fn merge(&mut self, other: ProgramScope) -> Result<()> {
+        let program = self.program_id;
+        merge_definitions(&mut self.consts, other.consts, "constant", program, |c| c.span)?;
+        merge_definitions(&mut self.structs, other.structs, "struct", program, |s| s.span)?;
+        merge_definitions(&mut self.mappings, other.mappings, "mapping", program, |m| m.span)?;
+        merge_definitions(&mut self.functions, other.functions, "function", program, |f| f.span)
+    }
+}
+
+/// Appends `other` to `existing`, erroring on the first name that `existing` already defines.
+fn merge_definitions<T>(
+    existing: &mut Vec<(Symbol, T)>,
+    other: Vec<(Symbol, T)>,
+    kind: &str,
+    program: ProgramId,
+    span: impl Fn(&T) -> Span,
+) -> Result<()> {
+    for (name, definition) in other {
+        if existing.iter().any(|(existing_name, _)| *existing_name == name) {
+            return Err(AstError::conflicting_definition_in_merge(kind, name, program, span(&definition)).into());
+        }
+        existing.push((name, definition));
+    }
+    Ok(())
+}
@@ -14,22 +14,59 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{NonNegativeNumber, Type};
+use crate::{Identifier, NonNegativeNumber, Type};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// The length of an array type, or the count of an array repeat expression.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArrayLength {
+    /// A whole number, e.g. `3` in `[u8; 3]`.
+    Literal(NonNegativeNumber),
+    /// The name of a `u32` constant, e.g. `N` in `[u8; N]`.
+    /// It is resolved to the value of the constant during type checking and loop unrolling.
+    Constant(Identifier),
+}
+
+impl ArrayLength {
+    /// Returns the value of the length.
+    /// Note that a length given by a constant is only known once it has been resolved.
+    pub fn value(&self) -> usize {
+        match self {
+            ArrayLength::Literal(length) => length.value(),
+            ArrayLength::Constant(name) => unreachable!("The array length `{name}` has not been resolved."),
+        }
+    }
+}
+
+impl From<NonNegativeNumber> for ArrayLength {
+    fn from(length: NonNegativeNumber) -> Self {
+        ArrayLength::Literal(length)
+    }
+}
+
+impl fmt::Display for ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArrayLength::Literal(length) => write!(f, "{length}"),
+            ArrayLength::Constant(name) => write!(f, "{name}"),
+        }
+    }
+}
+
 /// An array type.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ArrayType {
     element_type: Box<Type>,
-    length: NonNegativeNumber,
+    length: ArrayLength,
 }
 
 impl ArrayType {
     /// Creates a new array type.
-    pub fn new(element: Type, length: NonNegativeNumber) -> Self {
-        Self { element_type: Box::new(element), length }
+    pub fn new(element: Type, length: impl Into<ArrayLength>) -> Self {
+        Self { element_type: Box::new(element), length: length.into() }
     }
 
     /// Returns the element type of the array.
@@ -42,6 +79,11 @@ impl ArrayType {
         self.length.value()
     }
 
+    /// Returns the length of the array as written, which may be the name of a constant.
+    pub fn array_length(&self) -> &ArrayLength {
+        &self.length
+    }
+
     /// Returns the base element type of the array.
     pub fn base_element_type(&self) -> &Type {
         match self.element_type.as_ref() {
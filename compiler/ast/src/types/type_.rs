@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayType, Identifier, IntegerType, MappingType, TupleType};
+use crate::{ArrayLength, ArrayType, Identifier, IntegerType, MappingType, NonNegativeNumber, TupleType};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -86,6 +86,34 @@ impl Type {
             _ => false,
         }
     }
+
+    ///
+    /// Returns a copy of the type in which every array length given by a constant is replaced with its value.
+    ///
+    /// An array whose length `length_of` cannot resolve is replaced with `Type::Err`.
+    ///
+    pub fn resolve_array_lengths(&self, length_of: &impl Fn(&Identifier) -> Option<usize>) -> Type {
+        match self {
+            Type::Array(array_type) => {
+                let length = match array_type.array_length() {
+                    ArrayLength::Literal(length) => length.clone(),
+                    ArrayLength::Constant(name) => match length_of(name) {
+                        Some(length) => NonNegativeNumber::from(length),
+                        None => return Type::Err,
+                    },
+                };
+                Type::Array(ArrayType::new(array_type.element_type().resolve_array_lengths(length_of), length))
+            }
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(mapping_type.key.resolve_array_lengths(length_of)),
+                value: Box::new(mapping_type.value.resolve_array_lengths(length_of)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|element| element.resolve_array_lengths(length_of)).collect(),
+            )),
+            _ => self.clone(),
+        }
+    }
 }
 
 impl fmt::Display for Type {
@@ -14,12 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{GroupLiteral, Identifier, IntegerType, Literal, NodeID, Type};
+use crate::{GroupLiteral, IntegerType, Literal, Type};
 
 use leo_errors::{type_name, FlattenError, LeoError, Result};
-use leo_span::{Span, Symbol};
+use leo_span::Span;
 
-use indexmap::IndexMap;
 use std::{
     fmt::Display,
     num::ParseIntError,
@@ -179,25 +178,41 @@ macro_rules! implement_const_binary {
     };
 }
 
+/// A constant value of a primitive type, as produced by a literal or by folding constant operations.
+/// Each variant is displayed as its value alone, without a type suffix.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Value {
-    Input(Type, Identifier),
+    /// An address, e.g. `aleo1...`.
     Address(String, Span),
+    /// A boolean, `true` or `false`.
     Boolean(bool, Span),
-    Struct(Identifier, IndexMap<Symbol, Value>),
+    /// The decimal digits of a field element, e.g. `-5` for `-5field`.
     Field(String, Span),
+    /// A group element, either a single coordinate or a pair of coordinates.
     Group(Box<GroupLiteral>),
+    /// A signed 8-bit integer.
     I8(i8, Span),
+    /// A signed 16-bit integer.
     I16(i16, Span),
+    /// A signed 32-bit integer.
     I32(i32, Span),
+    /// A signed 64-bit integer.
     I64(i64, Span),
+    /// A signed 128-bit integer.
     I128(i128, Span),
+    /// An unsigned 8-bit integer.
     U8(u8, Span),
+    /// An unsigned 16-bit integer.
     U16(u16, Span),
+    /// An unsigned 32-bit integer.
     U32(u32, Span),
+    /// An unsigned 64-bit integer.
     U64(u64, Span),
+    /// An unsigned 128-bit integer.
     U128(u128, Span),
+    /// The decimal digits of a scalar, e.g. `1` for `1scalar`.
     Scalar(String, Span),
+    /// A string, without its surrounding quotes.
     String(String, Span),
 }
 
@@ -727,9 +742,7 @@ impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use Value::*;
         match self {
-            Input(type_, ident) => write!(f, "input var {}: {type_}", ident.name),
             Address(val, _) => write!(f, "{val}"),
-            Struct(val, _) => write!(f, "{}", val.name),
             Boolean(val, _) => write!(f, "{val}"),
             Field(val, _) => write!(f, "{val}"),
             Group(val) => write!(f, "{val}"),
@@ -843,10 +856,8 @@ impl From<&Value> for Type {
     fn from(v: &Value) -> Self {
         use Value::*;
         match v {
-            Input(type_, _) => type_.clone(),
             Address(_, _) => Type::Address,
             Boolean(_, _) => Type::Boolean,
-            Struct(ident, _) => Type::Identifier(*ident),
             Field(_, _) => Type::Field,
             Group(_) => Type::Group,
             I8(_, _) => Type::Integer(IntegerType::I8),
@@ -895,30 +906,3 @@ impl TryFrom<&Literal> for Value {
         })
     }
 }
-
-impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
-        use Value::*;
-        match v {
-            Input(_, _) => todo!("We need to test if this is hittable"),
-            Address(v, span) => Literal::Address(v, span, id),
-            Boolean(v, span) => Literal::Boolean(v, span, id),
-            Struct(_ident, _values) => todo!("We need to test if this is hittable"),
-            Field(v, span) => Literal::Field(v, span, id),
-            Group(v) => Literal::Group(v),
-            I8(v, span) => Literal::Integer(IntegerType::I8, v.to_string(), span, id),
-            I16(v, span) => Literal::Integer(IntegerType::I16, v.to_string(), span, id),
-            I32(v, span) => Literal::Integer(IntegerType::I32, v.to_string(), span, id),
-            I64(v, span) => Literal::Integer(IntegerType::I64, v.to_string(), span, id),
-            I128(v, span) => Literal::Integer(IntegerType::I128, v.to_string(), span, id),
-            U8(v, span) => Literal::Integer(IntegerType::U8, v.to_string(), span, id),
-            U16(v, span) => Literal::Integer(IntegerType::U16, v.to_string(), span, id),
-            U32(v, span) => Literal::Integer(IntegerType::U32, v.to_string(), span, id),
-            U64(v, span) => Literal::Integer(IntegerType::U64, v.to_string(), span, id),
-            U128(v, span) => Literal::Integer(IntegerType::U128, v.to_string(), span, id),
-            Scalar(v, span) => Literal::Scalar(v, span, id),
-            String(v, span) => Literal::String(v, span, id),
-        }
-    }
-}
@@ -54,6 +54,8 @@ pub struct Compiler<'a> {
     assigner: Assigner,
     /// The type table.
     type_table: TypeTable,
+    /// The guards of the calls to inline functions, recorded by the flattener for the function inliner.
+    guard_table: GuardTable,
 }
 
 impl<'a> Compiler<'a> {
@@ -69,6 +71,7 @@ impl<'a> Compiler<'a> {
         let node_builder = NodeBuilder::default();
         let assigner = Assigner::default();
         let type_table = TypeTable::default();
+        let guard_table = GuardTable::default();
         Self {
             handler,
             main_file_path,
@@ -81,6 +84,7 @@ impl<'a> Compiler<'a> {
             node_builder,
             assigner,
             type_table,
+            guard_table,
         }
     }
 
@@ -104,7 +108,15 @@ impl<'a> Compiler<'a> {
         let prg_sf = with_session_globals(|s| s.source_map.new_source(program_string, name));
 
         // Use the parser to construct the abstract syntax tree (ast).
-        self.ast = leo_parser::parse_ast(self.handler, &self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        let options =
+            leo_parser::ParserOptions { implicit_field_literals: self.compiler_options.build.implicit_field_literals };
+        self.ast = leo_parser::parse_ast_with_options(
+            self.handler,
+            &self.node_builder,
+            &prg_sf.src,
+            prg_sf.start_pos,
+            options,
+        )?;
 
         // If the program is imported, then check that the name of its program scope matches the file name.
         // Note that parsing enforces that there is exactly one program scope in a file.
@@ -231,6 +243,7 @@ impl<'a> Compiler<'a> {
             std::mem::take(&mut self.ast),
             symbol_table,
             &self.type_table,
+            &self.guard_table,
             &self.node_builder,
             &self.assigner,
         ))?;
@@ -266,6 +279,7 @@ impl<'a> Compiler<'a> {
             call_graph,
             &self.assigner,
             &self.type_table,
+            &self.guard_table,
         ))?;
         self.ast = ast;
 
@@ -320,6 +334,38 @@ impl<'a> Compiler<'a> {
         Ok((st, struct_graph, call_graph))
     }
 
+    /// Parses and type checks the main program file, without running any later passes or generating bytecode.
+    /// Every error and warning found along the way is emitted to the handler, so a handler created with
+    /// `Handler::new_with_buf` collects all of the program's diagnostics from a single, fast run.
+    ///
+    /// ```
+    /// # use leo_compiler::Compiler;
+    /// # use leo_errors::emitter::Handler;
+    /// # use leo_span::symbol::create_session_if_not_set_then;
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let directory = temp_dir.path().to_path_buf();
+    /// let path = directory.join("main.leo");
+    /// let program = "program test.aleo { transition main(a: u32) -> u32 { let b: u32 = a; return true; } }";
+    /// std::fs::write(&path, program).unwrap();
+    ///
+    /// create_session_if_not_set_then(|_| {
+    ///     let (handler, buffer) = Handler::new_with_buf();
+    ///     let mut compiler = Compiler::new("test".into(), "aleo".into(), &handler, path, directory, None);
+    ///     assert!(compiler.check().is_err());
+    ///
+    ///     // The mismatched return type, and the unused variable `b`.
+    ///     assert_eq!(handler.err_count(), 1);
+    ///     assert_eq!(handler.warning_count(), 1);
+    ///     assert!(buffer.extract_warnings().to_string().contains("The variable `b` is never read."));
+    /// });
+    /// ```
+    pub fn check(&mut self) -> Result<()> {
+        self.parse_program()?;
+        let symbol_table = self.symbol_table_pass()?;
+        self.type_checker_pass(symbol_table)?;
+        Ok(())
+    }
+
     /// Returns a compiled Leo program.
     pub fn compile(&mut self) -> Result<(SymbolTable, String)> {
         // Parse the program.
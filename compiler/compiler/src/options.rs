@@ -28,6 +28,9 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether unsuffixed integer literals, e.g. `1`, are parsed as field literals.
+    /// Suffixed literals, e.g. `1u32`, keep the type of their suffix.
+    pub implicit_field_literals: bool,
 }
 
 #[derive(Clone, Default)]
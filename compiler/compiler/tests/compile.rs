@@ -163,3 +163,25 @@ impl Runner for TestRunner {
 pub fn compiler_tests() {
     leo_test_framework::run_tests(&TestRunner, "compiler");
 }
+
+/// Returns the number of instructions in the bytecode generated for `program`.
+fn count_instructions(program: &str) -> usize {
+    let handler = Handler::default();
+    create_session_if_not_set_then(|_| {
+        let mut parsed = parse_program(&handler, program, None, None).unwrap();
+        let bytecode = compile_and_process(&mut parsed).unwrap();
+        bytecode.lines().filter(|line| line.contains(" into ")).count()
+    })
+}
+
+#[test]
+fn test_boolean_literal_operands_are_folded() {
+    let unfolded = count_instructions(
+        "program test.aleo { transition main(a: bool, b: bool, c: bool) -> bool { return (a && b) || c; } }",
+    );
+    let folded = count_instructions(
+        "program test.aleo { transition main(a: bool, b: bool, c: bool) -> bool { return (false && b) || c; } }",
+    );
+    assert_eq!(unfolded, 2);
+    assert_eq!(folded, 0);
+}
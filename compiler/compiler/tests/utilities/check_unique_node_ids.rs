@@ -119,7 +119,7 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
     }
 
     fn visit_struct_init(&mut self, input: &'a StructExpression, _: &Self::AdditionalInput) -> Self::Output {
-        let StructExpression { name, members, id, .. } = input;
+        let StructExpression { name, members, base, id, .. } = input;
         self.visit_identifier(name, &Default::default());
         for StructVariableInitializer { identifier, expression, id, .. } in members {
             self.visit_identifier(identifier, &Default::default());
@@ -128,6 +128,9 @@ impl<'a> ExpressionVisitor<'a> for CheckUniqueNodeIds<'a> {
             }
             self.check(*id);
         }
+        if let Some(base) = base {
+            self.visit_expression(base, &Default::default());
+        }
         self.check(*id);
     }
 
@@ -283,9 +286,12 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
         // Check the inputs.
         for in_ in input {
             match in_ {
-                Input::Internal(FunctionInput { identifier, type_, id, .. }) => {
+                Input::Internal(FunctionInput { identifier, type_, default, id, .. }) => {
                     self.visit_identifier(identifier, &Default::default());
                     self.check_ty(type_);
+                    if let Some(default) = default {
+                        self.visit_expression(default, &Default::default());
+                    }
                     self.check(*id);
                 }
                 Input::External(External { identifier, program_name, record, id, .. }) => {
@@ -85,22 +85,28 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                 .iter()
                 .map(|config| {
                     let config = config.as_mapping().expect("Expected the compiler configuration to be a mapping.");
-                    assert_eq!(
-                        config.len(),
-                        1,
-                        "A compiler configuration must have exactly one key-value pair. e.g. `dce_enabled`: true"
-                    );
+                    // Reject unknown keys, so that a misspelled option is not silently ignored.
+                    for (key, _) in config.iter() {
+                        let key = key.as_str().expect("Expected the configuration keys to be strings.");
+                        assert!(
+                            matches!(key, "dce_enabled" | "implicit_field_literals"),
+                            "Unknown compiler configuration key `{key}`."
+                        );
+                    }
+                    let get = |key: &str| {
+                        config
+                            .get(&serde_yaml::Value::String(key.to_string()))
+                            .map(|value| value.as_bool().expect("Expected value to be a boolean."))
+                    };
                     BuildOptions {
-                        dce_enabled: config
-                            .get(&serde_yaml::Value::String("dce_enabled".to_string()))
-                            .expect("Expected key `dce_enabled`")
-                            .as_bool()
-                            .expect("Expected value to be a boolean."),
+                        dce_enabled: get("dce_enabled").expect("Expected key `dce_enabled`"),
+                        // Unsuffixed integer literals are rejected unless a configuration opts in.
+                        implicit_field_literals: get("implicit_field_literals").unwrap_or(false),
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions { dce_enabled: true, implicit_field_literals: false }],
     }
 }
 
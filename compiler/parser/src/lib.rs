@@ -42,6 +42,17 @@ pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, st
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
 
+/// Creates a new AST from a given file path and source code text, using the given parser options.
+pub fn parse_ast_with_options(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    options: ParserOptions,
+) -> Result<Ast> {
+    Ok(Ast::new(parser::parse_with_options(handler, node_builder, source, start_pos, options)?))
+}
+
 /// Parses program inputs from the input file path
 pub fn parse_program_inputs(
     handler: &Handler,
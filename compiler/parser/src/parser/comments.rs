@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{span::BytePos, Span};
+
+/// Attaches each comment to the nearest statement or expression of `program`.
+///
+/// A comment is trailing for the node it directly follows on the same line,
+/// and otherwise leading for the node it directly precedes, ignoring whitespace and other comments.
+/// All remaining comments are detached.
+pub(crate) fn attach_comments(program: &Program, comments: Vec<Comment>, source: &str, start_pos: BytePos) -> Comments {
+    let mut collector = NodeCollector::default();
+    collector.visit_program(program);
+
+    // Blank out the comments, so that adjacent comments do not separate a comment from its node.
+    let offset = |pos: BytePos| (pos.0 - start_pos.0) as usize;
+    let mut blanked = source.as_bytes().to_vec();
+    for comment in &comments {
+        blanked[offset(comment.span.lo)..offset(comment.span.hi)].iter_mut().filter(|b| **b != b'\n').for_each(|b| {
+            *b = b' ';
+        });
+    }
+
+    let mut attached = Comments::default();
+    for comment in comments {
+        let (lo, hi) = (offset(comment.span.lo), offset(comment.span.hi));
+
+        // The end of the preceding code, if it is on the same line as the comment.
+        // Statement spans do not include the terminating semicolon, so it is skipped as well.
+        let end = blanked[..lo].iter().rposition(|b| !matches!(b, b' ' | b'\t' | b'\r')).map_or(0, |i| i + 1);
+        if end > 0 && blanked[end - 1] != b'\n' {
+            let end_of_statement = if blanked[end - 1] == b';' { end - 1 } else { end };
+            if let Some(id) = collector.find(|span| offset(span.hi) == end || offset(span.hi) == end_of_statement) {
+                attached.trailing.entry(id).or_default().push(comment);
+                continue;
+            }
+        }
+
+        // The start of the following code.
+        if let Some(start) = blanked[hi..].iter().position(|b| !b.is_ascii_whitespace()).map(|i| hi + i) {
+            if let Some(id) = collector.find(|span| offset(span.lo) == start) {
+                attached.leading.entry(id).or_default().push(comment);
+                continue;
+            }
+        }
+
+        attached.detached.push(comment);
+    }
+    attached
+}
+
+/// Collects the spans of all statements and expressions in pre-order, so that outer nodes come first.
+#[derive(Default)]
+struct NodeCollector {
+    nodes: Vec<(Span, NodeID)>,
+}
+
+impl NodeCollector {
+    /// Returns the id of the outermost node whose span satisfies `predicate`.
+    fn find(&self, predicate: impl Fn(&Span) -> bool) -> Option<NodeID> {
+        self.nodes.iter().find(|(span, _)| predicate(span)).map(|(_, id)| *id)
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for NodeCollector {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.nodes.push((input.span(), input.id()));
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {}
+}
+
+impl<'a> StatementVisitor<'a> for NodeCollector {
+    fn visit_statement(&mut self, input: &'a Statement) {
+        self.nodes.push((input.span(), input.id()));
+        match input {
+            Statement::Assert(stmt) => self.visit_assert(stmt),
+            Statement::Assign(stmt) => self.visit_assign(stmt),
+            Statement::Block(stmt) => self.visit_block(stmt),
+            Statement::Conditional(stmt) => self.visit_conditional(stmt),
+            Statement::Console(stmt) => self.visit_console(stmt),
+            Statement::Const(stmt) => self.visit_const(stmt),
+            Statement::Definition(stmt) => self.visit_definition(stmt),
+            Statement::Expression(stmt) => self.visit_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Return(stmt) => self.visit_return(stmt),
+        }
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for NodeCollector {}
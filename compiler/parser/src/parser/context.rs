@@ -20,7 +20,8 @@ use leo_ast::*;
 use leo_errors::{emitter::Handler, ParserError, ParserWarning, Result};
 use leo_span::{Span, Symbol};
 
-use std::{fmt::Display, mem};
+use indexmap::IndexMap;
+use std::{cell::RefCell, fmt::Display, mem, path::PathBuf, rc::Rc};
 
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
@@ -41,6 +42,19 @@ pub(crate) struct ParserContext<'a> {
     pub(crate) disallow_struct_construction: bool,
     /// true if parsing an identifier inside an input file.
     pub(crate) allow_identifier_underscores: bool,
+    /// true if unsuffixed integer literals are parsed as field literals instead of rejected.
+    pub(crate) implicit_field_literals: bool,
+    /// The import files parsed so far, keyed by canonical path.
+    /// Shared with the parsers of nested imports so that each file is parsed at most once.
+    pub(crate) imported_files: Rc<RefCell<IndexMap<PathBuf, Program>>>,
+    /// The canonical paths of the import files read and parsed so far, in the order they were loaded.
+    /// Shared with the parsers of nested imports, like `imported_files`.
+    pub(crate) loaded_files: Rc<RefCell<Vec<PathBuf>>>,
+    /// The directory that import files are read from.
+    /// When `None`, this is the `imports` directory in the current working directory.
+    pub(crate) imports_directory: Option<PathBuf>,
+    /// The comments stripped from the token stream, in source order.
+    pub(crate) comments: Vec<Comment>,
 }
 
 /// Dummy span used to appease borrow checker.
@@ -49,8 +63,17 @@ const DUMMY_EOF: SpannedToken = SpannedToken { token: Token::Eof, span: Span::du
 impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder, mut tokens: Vec<SpannedToken>) -> Self {
-        // Strip out comments.
-        tokens.retain(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)));
+        // Strip out comments, keeping them aside as trivia.
+        let mut comments = Vec::new();
+        tokens.retain(|x| {
+            let kind = match x.token {
+                Token::CommentLine(_) => CommentKind::Line,
+                Token::CommentBlock(_) => CommentKind::Block,
+                _ => return true,
+            };
+            comments.push(Comment { kind, text: x.token.to_string().trim_end().to_string(), span: x.span });
+            false
+        });
         // For performance we reverse so that we get cheap `.pop()`s.
         tokens.reverse();
 
@@ -60,6 +83,11 @@ impl<'a> ParserContext<'a> {
             node_builder,
             disallow_struct_construction: false,
             allow_identifier_underscores: false,
+            implicit_field_literals: false,
+            imported_files: Default::default(),
+            loaded_files: Default::default(),
+            imports_directory: None,
+            comments,
             prev_token: token.clone(),
             token,
             tokens,
@@ -165,6 +193,11 @@ impl<'a> ParserContext<'a> {
                 return Err(ParserError::tuple_index_must_be_whole_number(&self.token.token, self.token.span).into());
             }
 
+            // Reject value if it does not fit in a `usize`, rather than panicking.
+            if value.parse::<usize>().is_err() {
+                return Err(ParserError::whole_number_too_large(value, self.prev_token.span).into());
+            }
+
             Ok((NonNegativeNumber::from(value), self.prev_token.span))
         } else {
             Err(ParserError::unexpected(&self.token.token, "integer literal", self.token.span).into())
@@ -240,14 +273,6 @@ impl<'a> ParserContext<'a> {
         self.parse_list(Delimiter::Parenthesis, Some(Token::Comma), f)
     }
 
-    /// Parse a list separated by `,` and delimited by brackets.
-    pub(super) fn parse_bracket_comma_list<T>(
-        &mut self,
-        f: impl FnMut(&mut Self) -> Result<Option<T>>,
-    ) -> Result<(Vec<T>, bool, Span)> {
-        self.parse_list(Delimiter::Bracket, Some(Token::Comma), f)
-    }
-
     /// Returns true if the current token is `(`.
     pub(super) fn peek_is_left_par(&self) -> bool {
         matches!(self.token.token, Token::LeftParen)
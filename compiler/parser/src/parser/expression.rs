@@ -107,9 +107,17 @@ impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next tokens represent
     /// a binary OR expression.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_boolean_and_expression`].
+    /// Otherwise, tries to parse the next token using [`parse_boolean_xor_expression`].
     fn parse_boolean_or_expression(&mut self) -> Result<Expression> {
-        self.parse_bin_expr(&[Token::Or], Self::parse_boolean_and_expression)
+        self.parse_bin_expr(&[Token::Or], Self::parse_boolean_xor_expression)
+    }
+
+    /// Returns an [`Expression`] AST node if the next tokens represent
+    /// a binary XOR expression.
+    ///
+    /// Otherwise, tries to parse the next token using [`parse_boolean_and_expression`].
+    fn parse_boolean_xor_expression(&mut self) -> Result<Expression> {
+        self.parse_bin_expr(&[Token::Xor], Self::parse_boolean_and_expression)
     }
 
     /// Returns an [`Expression`] AST node if the next tokens represent a
@@ -136,6 +144,7 @@ impl ParserContext<'_> {
             Token::Rem => BinaryOperation::Rem,
             Token::Or => BinaryOperation::Or,
             Token::And => BinaryOperation::And,
+            Token::Xor => BinaryOperation::LogicalXor,
             Token::BitOr => BinaryOperation::BitwiseOr,
             Token::BitAnd => BinaryOperation::BitwiseAnd,
             Token::Pow => BinaryOperation::Pow,
@@ -545,7 +554,20 @@ impl ParserContext<'_> {
 
     /// Returns an [`Expression`] AST node if the next tokens represent an array initialization expression.
     fn parse_array_expression(&mut self) -> Result<Expression> {
-        let (elements, _, span) = self.parse_bracket_comma_list(|p| p.parse_expression().map(Some))?;
+        let open_span = self.expect(&Token::LeftSquare)?;
+
+        let mut elements = Vec::new();
+        if !self.check(&Token::RightSquare) {
+            let element = self.parse_expression()?;
+            if self.eat(&Token::Semicolon) {
+                return self.parse_array_repeat_expression(open_span, element);
+            }
+            elements.push(element);
+            while self.eat(&Token::Comma) && !self.check(&Token::RightSquare) {
+                elements.push(self.parse_expression()?);
+            }
+        }
+        let span = open_span + self.expect(&Token::RightSquare)?;
 
         match elements.is_empty() {
             // If the array expression is empty, return an error.
@@ -556,6 +578,35 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns an [`Expression`] AST node for the rest of an array repeat expression, e.g. `3]` in `[0u8; 3]`.
+    /// The count must be known at compile time, so it must be an integer literal or the name of a `u32` constant.
+    fn parse_array_repeat_expression(&mut self, open_span: Span, element: Expression) -> Result<Expression> {
+        let count = if self.check_int() && self.look_ahead(1, |t| t.token == Token::RightSquare) {
+            // An unsuffixed count, as in an array type, e.g. `[0u8; 3]`.
+            ArrayLength::Literal(self.eat_whole_number()?.0)
+        } else {
+            let count = self.parse_expression()?;
+            match &count {
+                Expression::Literal(Literal::Integer(IntegerType::U32, value, ..)) => {
+                    match value.replace('_', "").parse::<usize>() {
+                        Ok(value) => ArrayLength::Literal(NonNegativeNumber::from(value)),
+                        Err(_) => return Err(ParserError::array_length_not_constant(&count, count.span()).into()),
+                    }
+                }
+                Expression::Identifier(name) => ArrayLength::Constant(*name),
+                _ => return Err(ParserError::array_length_not_constant(&count, count.span()).into()),
+            }
+        };
+        let span = open_span + self.expect(&Token::RightSquare)?;
+
+        Ok(Expression::Repeat(RepeatExpression {
+            element: Box::new(element),
+            count,
+            span,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Returns a reference to the next token if it is a [`GroupCoordinate`], or [None] if
     /// the next token is not a [`GroupCoordinate`].
     fn peek_group_coordinate(&self, dist: &mut usize) -> Option<GroupCoordinate> {
@@ -642,14 +693,26 @@ impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// struct initialization expression.
     /// let foo = Foo { x: 1u8 };
+    /// let bar = Foo { x: 2u8, ..foo };
     pub fn parse_struct_init_expression(&mut self, identifier: Identifier) -> Result<Expression> {
-        let (members, _, end) =
-            self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| p.parse_struct_member().map(Some))?;
+        let mut base = None;
+        let (members, _, end) = self.parse_list(Delimiter::Brace, Some(Token::Comma), |p| {
+            // The base struct fills in the remaining members, so nothing may follow it.
+            if base.is_some() {
+                return Err(ParserError::struct_base_must_be_last(p.token.span).into());
+            }
+            if p.eat(&Token::DotDot) {
+                base = Some(Box::new(p.parse_expression()?));
+                return Ok(None);
+            }
+            p.parse_struct_member().map(Some)
+        })?;
 
         Ok(Expression::Struct(StructExpression {
             span: identifier.span + end,
             name: identifier,
             members,
+            base,
             id: self.node_builder.next_id(),
         }))
     }
@@ -702,6 +765,10 @@ impl ParserContext<'_> {
                         let int_ty = Self::token_to_int_type(suffix).expect("unknown int type token");
                         Expression::Literal(Literal::Integer(int_ty, value, full_span, self.node_builder.next_id()))
                     }
+                    // Literal without a suffix, e.g., `42`, is a field when implicit field literals are enabled.
+                    None if self.implicit_field_literals => {
+                        Expression::Literal(Literal::Field(value, span, self.node_builder.next_id()))
+                    }
                     None => return Err(ParserError::implicit_values_not_allowed(value, span).into()),
                 }
             }
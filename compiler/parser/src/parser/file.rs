@@ -15,7 +15,6 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::parse_ast;
 use leo_errors::{CompilerError, ParserError, Result};
 use leo_span::{source_map::FileName, symbol::with_session_globals};
 
@@ -87,9 +86,12 @@ impl ParserContext<'_> {
 
         // Tokenize and parse import file.
         // Todo: move this to a different module.
-        let mut import_file_path =
-            std::env::current_dir().map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?;
-        import_file_path.push("imports");
+        let mut import_file_path = match &self.imports_directory {
+            Some(directory) => directory.clone(),
+            None => std::env::current_dir()
+                .map_err(|err| CompilerError::cannot_open_cwd(err, self.token.span))?
+                .join("imports"),
+        };
         import_file_path.push(format!("{}.leo", import_name.name));
 
         // Throw an error if the import file doesn't exist.
@@ -97,21 +99,41 @@ impl ParserContext<'_> {
             return Err(CompilerError::import_not_found(import_file_path.display(), self.prev_token.span).into());
         }
 
+        // Reuse the AST if this file was already imported elsewhere in the import graph.
+        // The copy gets fresh node IDs, so that they stay unique across the whole AST.
+        let import_file_path =
+            import_file_path.canonicalize().map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
+        if let Some(program) = self.imported_files.borrow().get(&import_file_path) {
+            let program = FreshNodeIds::new(self.node_builder).reconstruct_program(program.clone());
+            return Ok((import_name.name, (program, start + end)));
+        }
+
         // Read the import file into string.
         // Todo: protect against cyclic imports.
         let program_string =
             fs::read_to_string(&import_file_path).map_err(|e| CompilerError::file_read_error(&import_file_path, e))?;
 
+        tracing::debug!("Parsing import file `{}`", import_file_path.display());
+        self.loaded_files.borrow_mut().push(import_file_path.clone());
+
         // Create import file name.
-        let name: FileName = FileName::Real(import_file_path);
+        let name: FileName = FileName::Real(import_file_path.clone());
 
         // Register the source (`program_string`) in the source map.
         let prg_sf = with_session_globals(|s| s.source_map.new_source(&program_string, name));
 
-        // Use the parser to construct the imported abstract syntax tree (ast).
-        let program_ast = parse_ast(self.handler, self.node_builder, &prg_sf.src, prg_sf.start_pos)?;
+        // Use a parser sharing the import cache to construct the imported abstract syntax tree (ast).
+        let mut parser =
+            ParserContext::new(self.handler, self.node_builder, crate::tokenize(&prg_sf.src, prg_sf.start_pos)?);
+        parser.imported_files = self.imported_files.clone();
+        parser.loaded_files = self.loaded_files.clone();
+        parser.imports_directory = self.imports_directory.clone();
+        parser.implicit_field_literals = self.implicit_field_literals;
+        let program = parser.parse_program()?;
+
+        self.imported_files.borrow_mut().insert(import_file_path, program.clone());
 
-        Ok((import_name.name, (program_ast.into_repr(), start + end)))
+        Ok((import_name.name, (program, start + end)))
     }
 
     /// Parsers a program scope `program foo.aleo { ... }`.
@@ -325,10 +347,14 @@ impl ParserContext<'_> {
         } else {
             let type_ = self.parse_type()?.0;
 
+            // Parse the default value, e.g. `= 1u8`, if any.
+            let default = if self.eat(&Token::Assign) { Some(self.parse_expression()?) } else { None };
+
             Ok(functions::Input::Internal(FunctionInput {
                 identifier: name,
                 mode,
                 type_,
+                default,
                 span: name.span,
                 id: self.node_builder.next_id(),
             }))
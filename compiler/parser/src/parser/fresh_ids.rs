@@ -0,0 +1,379 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+
+/// Gives every node of a copied AST fragment a fresh `NodeID`, so that the copy can be used alongside the original.
+/// For example, each importer of a cached import file gets a copy of its program with fresh node IDs.
+pub(crate) struct FreshNodeIds<'a> {
+    /// Counter used to generate the fresh node ids.
+    node_builder: &'a NodeBuilder,
+}
+
+impl<'a> FreshNodeIds<'a> {
+    /// Returns a new `FreshNodeIds` that takes its node ids from `node_builder`.
+    pub(crate) fn new(node_builder: &'a NodeBuilder) -> Self {
+        Self { node_builder }
+    }
+
+    /// Returns `identifier` with a fresh `NodeID`.
+    fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { id: self.node_builder.next_id(), ..identifier }
+    }
+
+    /// Returns `input` with fresh `NodeID`s.
+    fn input(&mut self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput {
+                identifier: self.identifier(input.identifier),
+                type_: self.type_(input.type_),
+                default: input.default.map(|default| self.reconstruct_expression(default).0),
+                id: self.node_builder.next_id(),
+                ..input
+            }),
+            Input::External(external) => Input::External(self.external(external)),
+        }
+    }
+
+    /// Returns `output` with fresh `NodeID`s.
+    fn output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => Output::Internal(FunctionOutput {
+                type_: self.type_(output.type_),
+                id: self.node_builder.next_id(),
+                ..output
+            }),
+            Output::External(external) => Output::External(self.external(external)),
+        }
+    }
+
+    /// Returns `external` with fresh `NodeID`s.
+    fn external(&self, external: External) -> External {
+        External {
+            identifier: self.identifier(external.identifier),
+            program_name: self.identifier(external.program_name),
+            record: self.identifier(external.record),
+            id: self.node_builder.next_id(),
+            ..external
+        }
+    }
+
+    /// Returns `type_` with a fresh `NodeID` for each identifier in it.
+    fn type_(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array) => {
+                Type::Array(ArrayType::new(self.type_(array.element_type().clone()), self.length(array.array_length())))
+            }
+            Type::Identifier(identifier) => Type::Identifier(self.identifier(identifier)),
+            Type::Mapping(mapping) => Type::Mapping(MappingType {
+                key: Box::new(self.type_(*mapping.key)),
+                value: Box::new(self.type_(*mapping.value)),
+            }),
+            Type::Tuple(tuple) => {
+                Type::Tuple(TupleType::new(tuple.elements().iter().map(|type_| self.type_(type_.clone())).collect()))
+            }
+            _ => type_,
+        }
+    }
+
+    /// Returns `length` with a fresh `NodeID` if it is the name of a constant.
+    fn length(&self, length: &ArrayLength) -> ArrayLength {
+        match length {
+            ArrayLength::Literal(length) => ArrayLength::Literal(length.clone()),
+            ArrayLength::Constant(name) => ArrayLength::Constant(self.identifier(*name)),
+        }
+    }
+}
+
+impl ExpressionReconstructor for FreshNodeIds<'_> {
+    type AdditionalOutput = ();
+
+    /// Reconstructs the expression, and then gives the expression itself a fresh `NodeID`.
+    fn reconstruct_expression(&mut self, input: Expression) -> (Expression, Self::AdditionalOutput) {
+        let (mut expression, _) = match input {
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::Array(array) => self.reconstruct_array(array),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Repeat(repeat) => self.reconstruct_repeat(repeat),
+            Expression::Struct(struct_) => self.reconstruct_struct_init(struct_),
+            Expression::Err(err) => (Expression::Err(err), ()),
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Literal(value) => self.reconstruct_literal(value),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Tuple(tuple) => self.reconstruct_tuple(tuple),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Unit(unit) => self.reconstruct_unit(unit),
+        };
+        expression.set_id(self.node_builder.next_id());
+        (expression, ())
+    }
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                ty: self.type_(input.ty),
+                name: self.identifier(input.name),
+                ..input
+            })),
+            (),
+        )
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: self.type_(input.ty),
+                name: self.identifier(input.name),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                ..input
+            })),
+            (),
+        )
+    }
+
+    fn reconstruct_member_access(&mut self, input: MemberAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(self.reconstruct_expression(*input.inner).0),
+                name: self.identifier(input.name),
+                ..input
+            })),
+            (),
+        )
+    }
+
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(self.reconstruct_expression(*input.function).0),
+                arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                external: input.external.map(|external| Box::new(self.reconstruct_expression(*external).0)),
+                ..input
+            }),
+            (),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.type_(input.type_),
+                ..input
+            }),
+            (),
+        )
+    }
+
+    fn reconstruct_repeat(&mut self, input: RepeatExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Repeat(RepeatExpression {
+                element: Box::new(self.reconstruct_expression(*input.element).0),
+                count: self.length(&input.count),
+                ..input
+            }),
+            (),
+        )
+    }
+
+    /// Unlike the default, this keeps shorthand initializers such as `x` in `Foo { x }` as they are.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.identifier(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: self.identifier(member.identifier),
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        span: member.span,
+                        id: self.node_builder.next_id(),
+                    })
+                    .collect(),
+                base: input.base.map(|base| Box::new(self.reconstruct_expression(*base).0)),
+                ..input
+            }),
+            (),
+        )
+    }
+}
+
+impl StatementReconstructor for FreshNodeIds<'_> {
+    /// Reconstructs the statement, and then gives the statement itself a fresh `NodeID`.
+    fn reconstruct_statement(&mut self, input: Statement) -> (Statement, Self::AdditionalOutput) {
+        let (mut statement, _) = match input {
+            Statement::Assert(assert) => self.reconstruct_assert(assert),
+            Statement::Assign(stmt) => self.reconstruct_assign(*stmt),
+            // Blocks and constant declarations are given a fresh `NodeID` when they are reconstructed.
+            Statement::Block(stmt) => return (Statement::Block(self.reconstruct_block(stmt).0), ()),
+            Statement::Const(stmt) => return self.reconstruct_const(stmt),
+            Statement::Conditional(stmt) => self.reconstruct_conditional(stmt),
+            Statement::Console(stmt) => self.reconstruct_console(stmt),
+            Statement::Definition(stmt) => self.reconstruct_definition(stmt),
+            Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
+            Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Return(stmt) => self.reconstruct_return(stmt),
+        };
+        statement.set_id(self.node_builder.next_id());
+        (statement, ())
+    }
+
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            })),
+            (),
+        )
+    }
+
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        (
+            Block {
+                statements: input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect(),
+                span: input.span,
+                id: self.node_builder.next_id(),
+            },
+            (),
+        )
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: self.identifier(input.place),
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: self.node_builder.next_id(),
+            }),
+            (),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                place: self.reconstruct_expression(input.place).0,
+                type_: self.type_(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                ..input
+            }),
+            (),
+        )
+    }
+
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: self.identifier(input.variable),
+                type_: self.type_(input.type_),
+                start: self.reconstruct_expression(input.start).0,
+                stop: self.reconstruct_expression(input.stop).0,
+                block: self.reconstruct_block(input.block).0,
+                ..input
+            })),
+            (),
+        )
+    }
+}
+
+impl ProgramReconstructor for FreshNodeIds<'_> {
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        ProgramScope {
+            program_id: ProgramId {
+                name: self.identifier(input.program_id.name),
+                network: self.identifier(input.program_id.network),
+            },
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        Function {
+            annotations: input
+                .annotations
+                .into_iter()
+                .map(|annotation| Annotation {
+                    identifier: self.identifier(annotation.identifier),
+                    id: self.node_builder.next_id(),
+                    ..annotation
+                })
+                .collect(),
+            variant: input.variant,
+            identifier: self.identifier(input.identifier),
+            input: input.input.into_iter().map(|input| self.input(input)).collect(),
+            output: input.output.into_iter().map(|output| self.output(output)).collect(),
+            output_type: self.type_(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: self.identifier(finalize.identifier),
+                input: finalize.input.into_iter().map(|input| self.input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.output(output)).collect(),
+                output_type: self.type_(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: self.node_builder.next_id(),
+            }),
+            span: input.span,
+            id: self.node_builder.next_id(),
+        }
+    }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            identifier: self.identifier(input.identifier),
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member {
+                    identifier: self.identifier(member.identifier),
+                    type_: self.type_(member.type_),
+                    id: self.node_builder.next_id(),
+                    ..member
+                })
+                .collect(),
+            id: self.node_builder.next_id(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            identifier: self.identifier(input.identifier),
+            key_type: self.type_(input.key_type),
+            value_type: self.type_(input.value_type),
+            id: self.node_builder.next_id(),
+            ..input
+        }
+    }
+}
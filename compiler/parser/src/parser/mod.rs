@@ -27,24 +27,75 @@ use leo_span::Span;
 
 use indexmap::IndexMap;
 use leo_span::span::BytePos;
-use std::unreachable;
+use std::{mem, path::PathBuf, unreachable};
+
+mod comments;
 
 mod context;
 pub(super) use context::ParserContext;
 
 mod expression;
 mod file;
+mod fresh_ids;
+pub(super) use fresh_ids::FreshNodeIds;
 mod input;
 mod statement;
 pub(super) mod type_;
 
+#[derive(Clone, Copy, Default)]
+pub struct ParserOptions {
+    /// Whether unsuffixed integer literals, e.g. `1`, are parsed as field literals.
+    pub implicit_field_literals: bool,
+}
+
 /// Creates a new program from a given file path and source code text.
 pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Program> {
+    parse_with_options(handler, node_builder, source, start_pos, ParserOptions::default())
+}
+
+/// Creates a new program from a given file path and source code text, using the given parser options.
+pub fn parse_with_options(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+    options: ParserOptions,
+) -> Result<Program> {
     let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+    tokens.implicit_field_literals = options.implicit_field_literals;
 
     tokens.parse_program()
 }
 
+/// Creates a new program from a given file path and source code text,
+/// along with the comments of the source attached to the nearest statement or expression.
+pub fn parse_with_comments(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<(Program, Comments)> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let program = tokens.parse_program()?;
+    let comments = comments::attach_comments(&program, mem::take(&mut tokens.comments), source, start_pos);
+    Ok((program, comments))
+}
+
+/// Parses a program, also returning the canonical paths of the import files that were loaded.
+/// Each path appears once, in the order the files were read and parsed.
+pub fn parse_with_loaded_files(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    source: &str,
+    start_pos: BytePos,
+) -> Result<(Program, Vec<PathBuf>)> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    let program = tokens.parse_program()?;
+    Ok((program, tokens.loaded_files.take()))
+}
+
 /// Parses an input file at the given file `path` and `source` code text.
 pub fn parse_input(
     handler: &Handler,
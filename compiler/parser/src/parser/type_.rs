@@ -87,8 +87,11 @@ impl ParserContext<'_> {
             let (element_type, _) = self.parse_type()?;
             // Parse the semi-colon.
             self.expect(&Token::Semicolon)?;
-            // Parse the length.
-            let (length, _) = self.eat_whole_number()?;
+            // Parse the length, which is either a whole number or the name of a `u32` constant.
+            let length = match self.eat_identifier() {
+                Some(name) => ArrayLength::Constant(name),
+                None => ArrayLength::Literal(self.eat_whole_number()?.0),
+            };
             // Parse the right bracket.
             self.expect(&Token::RightSquare)?;
             // Return the array type.
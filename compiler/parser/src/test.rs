@@ -16,12 +16,13 @@
 
 use crate::{tokenizer, ParserContext, SpannedToken};
 
-use leo_ast::{NodeBuilder, NodeID, Statement};
+use leo_ast::{Comment, Comments, Expression, Node, NodeBuilder, NodeID, Program, Statement};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
     symbol::{create_session_if_not_set_then, SessionGlobals},
     Span,
+    Symbol,
 };
 use leo_test_framework::{
     runner::{Namespace, ParseType, Runner},
@@ -234,3 +235,242 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+/// Parses a program `main` that imports `left` and `right`, which both import `shared`.
+/// Also returns the file names of the import files that were loaded, in the order they were loaded.
+fn parse_diamond_imports() -> Result<(Program, Vec<String>), String> {
+    let directory = tempfile::tempdir().unwrap();
+    let program = |name: &str, import: &str| format!("{import}program {name}.aleo {{ transition {name}() {{}} }}");
+    let shared = "program shared.aleo { struct Point { x: u8 } transition shared(a: u8) -> u8 { return a + 1u8; } }";
+    std::fs::write(directory.path().join("shared.leo"), shared).unwrap();
+    std::fs::write(directory.path().join("left.leo"), program("left", "import shared.leo;\n")).unwrap();
+    std::fs::write(directory.path().join("right.leo"), program("right", "import shared.leo;\n")).unwrap();
+    let main = program("main", "import left.leo;\nimport right.leo;\n");
+
+    create_session_if_not_set_then(|s| {
+        let sf = s.source_map.new_source(&main, FileName::Custom("main".into()));
+        let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).map_err(|x| x.to_string())?;
+        with_handler(tokens, |p| {
+            p.imports_directory = Some(directory.path().to_path_buf());
+            let program = p.parse_program()?;
+            let loaded = p.loaded_files.take();
+            Ok((program, loaded.iter().map(|path| path.file_name().unwrap().to_string_lossy().into_owned()).collect()))
+        })
+    })
+}
+
+/// Collects the node IDs in the JSON serialization of an AST.
+/// Node IDs are serialized as `id` fields, also inside the JSON strings that identifiers are serialized as,
+/// and as the last element of a literal, after its span.
+fn collect_node_ids(value: &serde_json::Value, ids: &mut Vec<NodeID>) {
+    use serde_json::Value;
+    match value {
+        Value::Object(fields) => fields.iter().for_each(|(key, value)| match (key.as_str(), value) {
+            ("id", Value::Number(id)) => ids.push(id.as_u64().unwrap() as NodeID),
+            ("id", Value::String(id)) => ids.push(id.parse().unwrap()),
+            _ => collect_node_ids(value, ids),
+        }),
+        Value::Array(elements) => match elements.as_slice() {
+            [.., Value::Object(span), Value::Number(id)] if span.contains_key("lo") => {
+                ids.push(id.as_u64().unwrap() as NodeID);
+                elements[..elements.len() - 1].iter().for_each(|element| collect_node_ids(element, ids));
+            }
+            _ => elements.iter().for_each(|element| collect_node_ids(element, ids)),
+        },
+        Value::String(string) if string.starts_with('{') => {
+            collect_node_ids(&serde_json::from_str(string).unwrap(), ids);
+        }
+        _ => {}
+    }
+}
+
+#[test]
+pub fn diamond_imports_parse_shared_file_once() {
+    create_session_if_not_set_then(|_| {
+        let (program, loaded) = parse_diamond_imports().unwrap();
+        assert_eq!(loaded, ["left.leo", "shared.leo", "right.leo"]);
+        assert_eq!(loaded.iter().filter(|name| *name == "shared.leo").count(), 1);
+
+        let shared = |import: &str| &program.imports[&Symbol::intern(import)].0.imports[&Symbol::intern("shared")].0;
+        let (left, right) = (shared("left"), shared("right"));
+
+        // A second parse would have registered the file in the source map again, giving `right` different spans.
+        assert_eq!(left.to_string(), right.to_string());
+        let spans = |program: &Program| program.program_scopes.values().map(|scope| scope.span).collect::<Vec<_>>();
+        assert_eq!(spans(left), spans(right));
+    })
+}
+
+#[test]
+pub fn diamond_imports_have_unique_node_ids() {
+    create_session_if_not_set_then(|_| {
+        let (program, _) = parse_diamond_imports().unwrap();
+
+        let mut ids = Vec::new();
+        collect_node_ids(&serde_json::to_value(&program).unwrap(), &mut ids);
+        let unique = ids.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(ids.len(), unique.len(), "duplicate node IDs in {ids:?}");
+    })
+}
+
+#[test]
+pub fn program_signatures() {
+    create_session_if_not_set_then(|s| {
+        let program = "program test.aleo {
+            transition foo(a: u32, public b: field) -> (u32, private bool) { return (a, b == 0field); }
+            inline bar() {}
+        }";
+        let sf = s.source_map.new_source(program, FileName::Custom("test".into()));
+        let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).unwrap();
+        let program = with_handler(tokens, |p| p.parse_program()).unwrap();
+
+        let signatures = program.signatures().iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(signatures, [
+            "transition foo(a: u32, public b: field) -> (u32, private boolean)",
+            "inline bar() -> ()"
+        ]);
+    })
+}
+
+#[test]
+pub fn public_inputs_in_declaration_order() {
+    create_session_if_not_set_then(|s| {
+        let program = "program test.aleo {
+            transition main(public b: u32, a: field, public c: bool) -> (u32, public field) { return (b, a); }
+        }";
+        let sf = s.source_map.new_source(program, FileName::Custom("test".into()));
+        let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).unwrap();
+        let program = with_handler(tokens, |p| p.parse_program()).unwrap();
+
+        let public_inputs = program.signatures()[0].public_inputs().iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        assert_eq!(public_inputs, ["public b: u32", "public c: boolean", "output 1: field"]);
+    })
+}
+
+#[test]
+pub fn comments_round_trip() {
+    // Parses `source` and returns the statements of its only function along with the comments.
+    fn parse(s: &SessionGlobals, source: &str) -> (Vec<Statement>, Comments) {
+        let (handler, _) = Handler::new_with_buf();
+        let sf = s.source_map.new_source(source, FileName::Custom("test".into()));
+        let (program, comments) =
+            crate::parse_with_comments(&handler, &NodeBuilder::default(), &sf.src, sf.start_pos).unwrap();
+        let scope = program.program_scopes.values().next().unwrap();
+        (scope.functions[0].1.block.statements.clone(), comments)
+    }
+
+    // Re-emits the program, with the comments of each statement on its own line.
+    fn format(statements: &[Statement], comments: &Comments) -> String {
+        let mut output = comments.detached.iter().map(|c| format!("{c}\n")).collect::<String>();
+        output.push_str("program test.aleo {\n    transition main(a: u32) {\n");
+        for statement in statements {
+            comments.leading(statement.id()).iter().for_each(|c| output.push_str(&format!("        {c}\n")));
+            output.push_str(&format!("        {statement}"));
+            comments.trailing(statement.id()).iter().for_each(|c| output.push_str(&format!(" {c}")));
+            output.push('\n');
+        }
+        output.push_str("    }\n}\n");
+        output
+    }
+
+    create_session_if_not_set_then(|s| {
+        let texts = |comments: &[Comment]| comments.iter().map(|c| c.text.clone()).collect::<Vec<_>>();
+
+        let (statements, comments) = parse(
+            s,
+            "// The header.
+program test.aleo {
+    transition main(a: u32) {
+        // The sum.
+        let b: u32 = a + a; // Doubled.
+        /* Reset. */ b = 0u32;
+        let c: u32 = /* Seed. */ a * b;
+    }
+}",
+        );
+        assert_eq!(comments.len(), 5);
+        assert_eq!(texts(&comments.detached), ["// The header."]);
+        assert_eq!(texts(comments.leading(statements[0].id())), ["// The sum."]);
+        assert_eq!(texts(comments.trailing(statements[0].id())), ["// Doubled."]);
+        assert_eq!(texts(comments.leading(statements[1].id())), ["/* Reset. */"]);
+        let Statement::Definition(definition) = &statements[2] else { panic!("expected a definition") };
+        assert_eq!(texts(comments.leading(definition.value.id())), ["/* Seed. */"]);
+
+        // Comments on statements survive re-emitting and re-parsing the program.
+        let formatted = format(&statements, &comments);
+        let (reparsed_statements, reparsed_comments) = parse(s, &formatted);
+        assert_eq!(format(&reparsed_statements, &reparsed_comments), formatted);
+        assert_eq!(texts(&reparsed_comments.detached), ["// The header."]);
+        for (before, after) in statements.iter().zip(&reparsed_statements) {
+            assert_eq!(texts(comments.leading(before.id())), texts(reparsed_comments.leading(after.id())));
+            assert_eq!(texts(comments.trailing(before.id())), texts(reparsed_comments.trailing(after.id())));
+        }
+    })
+}
+
+#[test]
+pub fn merge_programs() {
+    create_session_if_not_set_then(|s| {
+        let (handler, _) = Handler::new_with_buf();
+        let node_builder = NodeBuilder::default();
+        let parse = |name: &str, source: &str| {
+            let sf = s.source_map.new_source(source, FileName::Custom(name.into()));
+            let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).unwrap();
+            ParserContext::new(&handler, &node_builder, tokens).parse_program().unwrap()
+        };
+        let user = parse("user", "program test.aleo { transition main(a: u32) -> u32 { return helper(a); } }");
+        let library = parse(
+            "library",
+            "program test.aleo { struct Pair { a: u32, b: u32 } inline helper(a: u32) -> u32 { return a + 1u32; } }",
+        );
+        let other = parse("other", "program other.aleo { transition main() {} }");
+        let conflicting = parse("conflicting", "program test.aleo { inline helper(a: u32) -> u32 { return a; } }");
+
+        let merged = user.merge(library).unwrap();
+        let scope = &merged.program_scopes[&Symbol::intern("test")];
+        let functions = scope.functions.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+        assert_eq!(functions, ["main", "helper"]);
+        assert_eq!(scope.structs[0].0, Symbol::intern("Pair"));
+
+        // Scopes of different programs are kept apart, even if they define the same names.
+        let merged = merged.merge(other).unwrap();
+        assert_eq!(merged.program_scopes.len(), 2);
+
+        let err = merged.merge(conflicting).unwrap_err().to_string();
+        assert!(err.contains("function `helper` is already defined in `test.aleo`"), "{err}");
+    });
+}
+
+#[test]
+pub fn value_display() {
+    create_session_if_not_set_then(|s| {
+        let address = "aleo1qnr4dkkvkgfqph0vzc3y6z2eu975wnpz2925ntjccd5cfqxtyu8s7pyjh9";
+        let cases = [
+            (address.to_string(), address),
+            ("true".to_string(), "true"),
+            ("-5field".to_string(), "-5"),
+            ("2group".to_string(), "2"),
+            ("-8i8".to_string(), "-8"),
+            ("16i16".to_string(), "16"),
+            ("-32i32".to_string(), "-32"),
+            ("64i64".to_string(), "64"),
+            ("-128i128".to_string(), "-128"),
+            ("8u8".to_string(), "8"),
+            ("16u16".to_string(), "16"),
+            ("1_000u32".to_string(), "1000"),
+            ("64u64".to_string(), "64"),
+            ("128u128".to_string(), "128"),
+            ("1scalar".to_string(), "1"),
+            ("\"leo\"".to_string(), "leo"),
+        ];
+        for (source, expected) in cases {
+            let sf = s.source_map.new_source(&source, FileName::Custom("value".into()));
+            let tokens = tokenizer::tokenize(&sf.src, sf.start_pos).unwrap();
+            let value = match with_handler(tokens, |p| p.parse_expression()).unwrap() {
+                Expression::Literal(literal) => leo_ast::Value::try_from(&literal).unwrap(),
+                expression => panic!("`{source}` did not parse to a literal: {expression}"),
+            };
+            assert_eq!(value.to_string(), expected, "{source}");
+        }
+    });
+}
@@ -177,6 +177,49 @@ impl Token {
         Ok((int.len(), Token::Integer(int)))
     }
 
+    /// Returns a tuple: [(literal length, integer token)] if a hexadecimal field literal can be eaten, otherwise returns an error.
+    /// The hexadecimal digits are converted to a decimal integer token, so that the `field` suffix can be eaten as usual.
+    /// Hexadecimal numbers are only allowed in field literals, e.g., `0x10field`.
+    /// If the last digit is a letter, it must be separated from the suffix by an `_`, e.g., `0xff_field`,
+    /// since otherwise `0xffield` could be read as either `0xf` or `0xff`.
+    fn eat_hex_field(input: &str) -> Result<(usize, Token)> {
+        // Skip the `0x` prefix.
+        let rest = &input[2..];
+        let mut len = rest.find(|c: char| !(c.is_ascii_hexdigit() || c == '_')).unwrap_or(rest.len());
+
+        // A trailing `f` may be the start of the `field` suffix, e.g., `0x1field`.
+        if rest[..len].ends_with('f') && rest[len..].starts_with("ield") {
+            len -= 1;
+        }
+
+        let digits = rest[..len].chars().filter(|c| *c != '_').collect::<String>();
+        if digits.is_empty() || !rest[len..].starts_with("field") {
+            return Err(ParserError::lexer_hex_number_provided("0x").into());
+        }
+        if rest[..len].ends_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(ParserError::lexer_hex_field_suffix_not_separated(&input[..len + 7]).into());
+        }
+
+        // Convert the hexadecimal digits into decimal digits, stored least significant first.
+        let mut decimal = vec![0u32];
+        for digit in digits.chars().filter_map(|c| c.to_digit(16)) {
+            let mut carry = digit;
+            for d in decimal.iter_mut() {
+                let value = *d * 16 + carry;
+                *d = value % 10;
+                carry = value / 10;
+            }
+            while carry > 0 {
+                decimal.push(carry % 10);
+                carry /= 10;
+            }
+        }
+        let int = decimal.iter().rev().filter_map(|d| char::from_digit(*d, 10)).collect();
+
+        // + 2 to account for the `0x` prefix.
+        Ok((len + 2, Token::Integer(int)))
+    }
+
     /// Returns a tuple: [(token length, token)] if the next token can be eaten, otherwise returns an error.
     /// The next token can be eaten if the bytes at the front of the given `input` string can be scanned into a token.
     pub(crate) fn eat(input: &str) -> Result<(usize, Token)> {
@@ -267,6 +310,7 @@ impl Token {
                 return Ok((string.len() + 2, Token::StaticString(string)));
             }
 
+            '0' if input_str.starts_with("0x") => return Self::eat_hex_field(input_str),
             x if x.is_ascii_digit() => return Self::eat_integer(&mut input),
             '!' => return match_two(&mut input, Token::Not, '=', Token::NotEq),
             '?' => return match_one(&mut input, Token::Question),
@@ -368,7 +412,7 @@ impl Token {
                     Token::OrAssign,
                 );
             }
-            '^' => return match_two(&mut input, Token::BitXor, '=', Token::BitXorAssign),
+            '^' => return match_three(&mut input, Token::BitXor, '=', Token::BitXorAssign, '^', Token::Xor),
             '@' => return Ok((1, Token::At)),
             _ => (),
         }
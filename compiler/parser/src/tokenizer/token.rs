@@ -40,6 +40,7 @@ pub enum Token {
     AndAssign,
     Or,
     OrAssign,
+    Xor,
     BitAnd,
     BitAndAssign,
     BitOr,
@@ -275,6 +276,7 @@ impl fmt::Display for Token {
             AndAssign => write!(f, "&&="),
             Or => write!(f, "||"),
             OrAssign => write!(f, "||="),
+            Xor => write!(f, "^^"),
             BitAnd => write!(f, "&"),
             BitAndAssign => write!(f, "&="),
             BitOr => write!(f, "|"),
@@ -380,8 +382,6 @@ pub enum Delimiter {
     Parenthesis,
     /// `{ ... }`
     Brace,
-    /// `[ ... ]`
-    Bracket,
 }
 
 impl Delimiter {
@@ -390,7 +390,6 @@ impl Delimiter {
         match self {
             Self::Parenthesis => (Token::LeftParen, Token::RightParen),
             Self::Brace => (Token::LeftCurly, Token::RightCurly),
-            Self::Bracket => (Token::LeftSquare, Token::RightSquare),
         }
     }
 }
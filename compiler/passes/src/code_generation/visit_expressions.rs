@@ -30,6 +30,7 @@ use leo_ast::{
     Identifier,
     Literal,
     MemberAccess,
+    RepeatExpression,
     StructExpression,
     TernaryExpression,
     TupleExpression,
@@ -55,6 +56,7 @@ impl<'a> CodeGenerator<'a> {
             Expression::Binary(expr) => self.visit_binary(expr),
             Expression::Call(expr) => self.visit_call(expr),
             Expression::Cast(expr) => self.visit_cast(expr),
+            Expression::Repeat(expr) => self.visit_repeat(expr),
             Expression::Struct(expr) => self.visit_struct_init(expr),
             Expression::Err(expr) => self.visit_err(expr),
             Expression::Identifier(expr) => self.visit_identifier(expr),
@@ -77,6 +79,10 @@ impl<'a> CodeGenerator<'a> {
         unreachable!("`ErrExpression`s should not be in the AST at this phase of compilation.")
     }
 
+    fn visit_repeat(&mut self, _input: &'a RepeatExpression) -> (String, String) {
+        unreachable!("`RepeatExpression`s should not be in the AST at this phase of compilation.")
+    }
+
     fn visit_value(&mut self, input: &'a Literal) -> (String, String) {
         (format!("{input}"), String::new())
     }
@@ -88,6 +94,9 @@ impl<'a> CodeGenerator<'a> {
         let opcode = match input.op {
             BinaryOperation::Add => String::from("add"),
             BinaryOperation::AddWrapped => String::from("add.w"),
+            BinaryOperation::AddSaturating | BinaryOperation::SubSaturating => {
+                unreachable!("Saturating operations should not be in the AST at this phase of compilation.")
+            }
             BinaryOperation::And => String::from("and"),
             BinaryOperation::BitwiseAnd => String::from("and"),
             BinaryOperation::Div => String::from("div"),
@@ -97,6 +106,7 @@ impl<'a> CodeGenerator<'a> {
             BinaryOperation::Gt => String::from("gt"),
             BinaryOperation::Lte => String::from("lte"),
             BinaryOperation::Lt => String::from("lt"),
+            BinaryOperation::LogicalXor => String::from("xor"),
             BinaryOperation::Mod => String::from("mod"),
             BinaryOperation::Mul => String::from("mul"),
             BinaryOperation::MulWrapped => String::from("mul.w"),
@@ -150,15 +160,19 @@ impl<'a> CodeGenerator<'a> {
     }
 
     fn visit_array(&mut self, input: &'a ArrayExpression) -> (String, String) {
-        let (expression_operands, mut instructions) =
-            input.elements.iter().map(|expr| self.visit_expression(expr)).fold(
-                (String::new(), String::new()),
-                |(mut operands, mut instructions), (operand, operand_instructions)| {
-                    operands.push_str(&operand);
-                    instructions.push_str(&operand_instructions);
-                    (operands, instructions)
-                },
-            );
+        // Visit each element and accumulate instructions from expressions.
+        // Note that the operands must be separated by whitespace, since an operand may be a member or array access.
+        let mut instructions = String::new();
+        let expression_operands = input
+            .elements
+            .iter()
+            .map(|expr| {
+                let (operand, operand_instructions) = self.visit_expression(expr);
+                instructions.push_str(&operand_instructions);
+                operand
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
 
         // Construct the destination register.
         let destination_register = format!("r{}", self.next_register);
@@ -29,6 +29,12 @@ pub struct Assigner {
 
 impl Assigner {
     /// Return a new unique `Symbol` from a `&str`.
+    ///
+    /// The symbol is formatted as `{arg}{separator}{n}`, where `n` is the number of symbols this assigner has
+    /// produced before, starting at `0`. For example, SSA renames the second version of `x` to `x$1` if one
+    /// symbol was created before it. Since `$` cannot appear in Leo identifiers, these names never collide with
+    /// user-defined ones. This format is stable; tools that map generated names back to source variables may
+    /// rely on it.
     pub fn unique_symbol(&self, arg: impl Display, separator: impl Display) -> Symbol {
         self.inner.borrow_mut().unique_symbol(arg, separator)
     }
@@ -66,3 +72,20 @@ impl AssignerInner {
         }))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn test_unique_symbol_format() {
+        create_session_if_not_set_then(|_| {
+            let assigner = Assigner::default();
+            assert_eq!(assigner.unique_symbol("x", "$").to_string(), "x$0");
+            assert_eq!(assigner.unique_symbol("$var", "$").to_string(), "$var$1");
+            assert_eq!(assigner.unique_symbol("a", "$index$0$").to_string(), "a$index$0$2");
+        });
+    }
+}
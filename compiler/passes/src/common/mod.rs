@@ -20,6 +20,9 @@ pub use assigner::*;
 pub mod graph;
 pub use graph::*;
 
+pub mod guard_table;
+pub use guard_table::*;
+
 pub mod rename_table;
 pub use rename_table::*;
 
@@ -18,7 +18,7 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use leo_ast::{Mode, Type};
+use leo_ast::{Literal, Mode, Type};
 use leo_span::Span;
 
 /// An enumeration of the different types of variable type.
@@ -50,6 +50,9 @@ pub struct VariableSymbol {
     pub span: Span,
     /// The type of declaration for the variable.
     pub declaration: VariableType,
+    /// The value of the variable, if it is a constant declared with a literal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Literal>,
 }
 
 impl Display for VariableSymbol {
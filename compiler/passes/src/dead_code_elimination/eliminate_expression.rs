@@ -77,6 +77,7 @@ impl ExpressionReconstructor for DeadCodeEliminator<'_> {
                         id: member.id,
                     })
                     .collect(),
+                base: None,
                 span: input.span,
                 id: input.id,
             }),
@@ -14,22 +14,145 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Flattener;
+use crate::{Flattener, FunctionSymbol};
 
 use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    CallExpression,
     Expression,
     ExpressionReconstructor,
+    IntegerType,
+    Literal,
     Node,
     Statement,
     StructExpression,
     StructVariableInitializer,
     TernaryExpression,
     Type,
+    UnaryExpression,
+    UnaryOperation,
+    Variant,
 };
+use leo_span::sym;
 
 impl ExpressionReconstructor for Flattener<'_> {
     type AdditionalOutput = Vec<Statement>;
 
+    /// Reconstructs arithmetic expressions over arrays, accumulating any statements that are generated.
+    /// This is necessary because Aleo instructions do not support arithmetic over arrays.
+    /// The operation is applied element-wise, producing a new array.
+    /// Saturating operations are lowered into wrapping arithmetic, since Aleo instructions do not support them.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if let (
+            BinaryOperation::AddSaturating | BinaryOperation::SubSaturating,
+            Expression::Identifier(first),
+            Expression::Identifier(second),
+        ) = (input.op, &*input.left, &*input.right)
+        {
+            if let Some(Type::Integer(integer_type)) = self.type_table.get(&input.id) {
+                return self.saturating_binary(integer_type, input.op, first, second);
+            }
+        }
+
+        if let (
+            BinaryOperation::Add | BinaryOperation::Sub | BinaryOperation::Mul,
+            Expression::Identifier(first),
+            Expression::Identifier(second),
+        ) = (input.op, &*input.left, &*input.right)
+        {
+            if let Some(Type::Array(array_type)) = self.type_table.get(&first.id()) {
+                return self.binary_array(&array_type, input.op, first, second);
+            }
+        }
+
+        // Otherwise, reconstruct the operands.
+        let (left, mut statements) = self.reconstruct_expression(*input.left);
+        let (right, stmts) = self.reconstruct_expression(*input.right);
+        statements.extend(stmts);
+
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(left),
+                right: Box::new(right),
+                op: input.op,
+                span: input.span,
+                id: input.id,
+            }),
+            statements,
+        )
+    }
+
+    /// Reconstructs calls to the `len`, `concat`, `any`, and `all` array built-ins, accumulating any generated statements.
+    /// `len(a)` is replaced by the length of `a` as a `u32` literal.
+    /// `concat(a, b)` is replaced by an array containing the elements of `a` followed by the elements of `b`.
+    /// `any(a)` and `all(a)` are replaced by the elements of `a` joined with `||` and `&&`, respectively.
+    /// Note that a user-defined function of the same name takes precedence over a built-in.
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        // The name of the array built-in being called, if any.
+        let builtin = match (&*input.function, &input.external) {
+            (Expression::Identifier(function), None) if self.symbol_table.lookup_fn_symbol(function.name).is_none() => {
+                Some(function.name)
+            }
+            _ => None,
+        };
+
+        match (&*input.function, input.arguments.as_slice()) {
+            (_, [Expression::Identifier(array)]) if builtin == Some(sym::len) => {
+                match self.type_table.get(&array.id()) {
+                    Some(Type::Array(array_type)) => (
+                        Expression::Literal(Literal::Integer(
+                            IntegerType::U32,
+                            array_type.length().to_string(),
+                            input.span,
+                            input.id,
+                        )),
+                        Default::default(),
+                    ),
+                    _ => unreachable!("Type checking guarantees that the argument of `len` is an array."),
+                }
+            }
+            (_, [Expression::Identifier(first), Expression::Identifier(second)]) if builtin == Some(sym::concat) => {
+                match self.type_table.get(&input.id) {
+                    Some(Type::Array(output)) => self.concat_arrays(&output, first, second),
+                    _ => unreachable!("Type checking guarantees that `concat` returns an array."),
+                }
+            }
+            (Expression::Identifier(function), [Expression::Identifier(array)])
+                if matches!(builtin, Some(sym::any | sym::all)) =>
+            {
+                let operation = if function.name == sym::any { BinaryOperation::Or } else { BinaryOperation::And };
+                match self.type_table.get(&array.id()) {
+                    Some(Type::Array(array_type)) => self.fold_boolean_array(&array_type, array, operation),
+                    _ => unreachable!("Type checking guarantees that the argument of `{function}` is an array."),
+                }
+            }
+            _ => {
+                // Record the guard of a call to an inline function.
+                // Once the call is inlined, the asserts of the callee are only checked if this guard holds.
+                if let (Expression::Identifier(function), None) = (&*input.function, &input.external) {
+                    if let Some(FunctionSymbol { variant: Variant::Inline, .. }) =
+                        self.symbol_table.lookup_fn_symbol(function.name)
+                    {
+                        if let Some(guard) = self.construct_reachability_guard() {
+                            self.guard_table.insert(input.id, guard);
+                        }
+                    }
+                }
+                (
+                    Expression::Call(CallExpression {
+                        function: Box::new(self.reconstruct_expression(*input.function).0),
+                        arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
+                        external: input.external,
+                        span: input.span,
+                        id: input.id,
+                    }),
+                    Default::default(),
+                )
+            }
+        }
+    }
+
     /// Reconstructs a struct init expression, flattening any tuples in the expression.
     fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
         let mut statements = Vec::new();
@@ -50,7 +173,16 @@ impl ExpressionReconstructor for Flattener<'_> {
             });
         }
 
-        (Expression::Struct(StructExpression { name: input.name, members, span: input.span, id: input.id }), statements)
+        (
+            Expression::Struct(StructExpression {
+                name: input.name,
+                members,
+                base: None,
+                span: input.span,
+                id: input.id,
+            }),
+            statements,
+        )
     }
 
     /// Reconstructs ternary expressions over arrays, structs, and tuples, accumulating any statements that are generated.
@@ -123,4 +255,20 @@ impl ExpressionReconstructor for Flattener<'_> {
             }
         }
     }
+
+    /// Reconstructs unary operations over arrays, accumulating any statements that are generated.
+    /// This is necessary because Aleo instructions do not support unary operations over arrays.
+    /// The operation is applied element-wise, producing a new array.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if let (UnaryOperation::Not, Expression::Identifier(receiver)) = (input.op, &*input.receiver) {
+            if let Some(Type::Array(array_type)) = self.type_table.get(&receiver.id()) {
+                return self.unary_array(&array_type, input.op, receiver);
+            }
+        }
+
+        // Otherwise, reconstruct the receiver.
+        let (receiver, statements) = self.reconstruct_expression(*input.receiver);
+
+        (Expression::Unary(UnaryExpression { receiver: Box::new(receiver), ..input }), statements)
+    }
 }
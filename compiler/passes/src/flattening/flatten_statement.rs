@@ -58,6 +58,7 @@ impl StatementReconstructor for Flattener<'_> {
     /// assert(!(condition1 && condition2) || foo);
     /// ```
     /// which is equivalent to the logical formula `(condition1 /\ condition2) ==> foo`.
+    /// Similarly, assert statements that follow a conditional return are only checked if that return was not taken.
     fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
         let mut statements = Vec::new();
 
@@ -89,7 +90,7 @@ impl StatementReconstructor for Flattener<'_> {
         };
 
         // Add the appropriate guards.
-        match self.construct_guard() {
+        match self.construct_reachability_guard() {
             // If the condition stack is empty, we can return the flattened assert statement.
             None => (Statement::Assert(assert), statements),
             // Otherwise, we need to join the guard with the expression in the flattened assert statement.
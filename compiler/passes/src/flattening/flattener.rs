@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, SymbolTable, TypeTable};
+use crate::{Assigner, GuardTable, SymbolTable, TypeTable};
 
 use leo_ast::{
     AccessExpression,
@@ -44,6 +44,8 @@ use leo_ast::{
     TupleExpression,
     TupleType,
     Type,
+    UnaryExpression,
+    UnaryOperation,
     UnitExpression,
 };
 
@@ -52,6 +54,8 @@ pub struct Flattener<'a> {
     pub(crate) symbol_table: &'a SymbolTable,
     /// A mapping between node IDs and their types.
     pub(crate) type_table: &'a TypeTable,
+    /// A mapping between the node IDs of calls to inline functions and the guards they are executed under.
+    pub(crate) guard_table: &'a GuardTable,
     /// A counter used to generate unique node IDs.
     pub(crate) node_builder: &'a NodeBuilder,
     /// A struct used to construct (unique) assignment statements.
@@ -69,10 +73,19 @@ impl<'a> Flattener<'a> {
     pub(crate) fn new(
         symbol_table: &'a SymbolTable,
         type_table: &'a TypeTable,
+        guard_table: &'a GuardTable,
         node_builder: &'a NodeBuilder,
         assigner: &'a Assigner,
     ) -> Self {
-        Self { symbol_table, type_table, node_builder, assigner, condition_stack: Vec::new(), returns: Vec::new() }
+        Self {
+            symbol_table,
+            type_table,
+            guard_table,
+            node_builder,
+            assigner,
+            condition_stack: Vec::new(),
+            returns: Vec::new(),
+        }
     }
 
     /// Clears the state associated with `ReturnStatements`, returning the ones that were previously stored.
@@ -106,6 +119,50 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Constructs a guard that is true on the current execution path, provided that no earlier return was taken.
+    /// Statements that follow a conditional return must only take effect if that return was not taken.
+    /// For example, an assert statement preceded by `if c { return; }` is guarded by `!c`.
+    pub(crate) fn construct_reachability_guard(&mut self) -> Option<Expression> {
+        // Collect the negations of the guards of the returns encountered so far.
+        // Note that a return without a guard ends the function, so it cannot precede another statement.
+        let not_returned = self
+            .returns
+            .iter()
+            .filter_map(|(guard, _)| guard.clone())
+            .map(|guard| {
+                Expression::Unary(UnaryExpression {
+                    op: UnaryOperation::Not,
+                    receiver: Box::new(guard),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the unary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, Type::Boolean);
+                        id
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Conjoin them with the guard of the current execution path.
+        self.construct_guard().into_iter().chain(not_returned).reduce(|acc, condition| {
+            Expression::Binary(BinaryExpression {
+                op: BinaryOperation::And,
+                left: Box::new(acc),
+                right: Box::new(condition),
+                span: Default::default(),
+                id: {
+                    // Create a new node ID for the binary expression.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                },
+            })
+        })
+    }
+
     /// Fold guards and expressions into a single expression.
     /// Note that this function assumes that at least one guard is present.
     pub(crate) fn fold_guards(
@@ -269,6 +326,382 @@ impl<'a> Flattener<'a> {
         }
     }
 
+    /// Constructs an element-wise binary operation over two arrays of the same type.
+    /// For example, `a + b`, where `a` and `b` are both `[u32; 2]`, is flattened into the following:
+    /// ```leo
+    /// let var$0 = a[0u32];
+    /// let var$1 = b[0u32];
+    /// let var$2 = var$0 + var$1;
+    /// let var$3 = a[1u32];
+    /// let var$4 = b[1u32];
+    /// let var$5 = var$3 + var$4;
+    /// let var$6 = [var$2, var$5];
+    /// var$6
+    /// ```
+    pub(crate) fn binary_array(
+        &mut self,
+        array: &ArrayType,
+        op: BinaryOperation,
+        first: &Identifier,
+        second: &Identifier,
+    ) -> (Expression, Vec<Statement>) {
+        // Initialize a vector to accumulate any statements generated.
+        let mut statements = Vec::new();
+        // For each array element, construct a new binary expression.
+        let elements = (0..array.length())
+            .map(|i| {
+                // Create assignment statements for the element of each operand.
+                let (first, stmt) = self.unique_array_element_assign_statement(array, first, i);
+                statements.push(stmt);
+                let (second, stmt) = self.unique_array_element_assign_statement(array, second, i);
+                statements.push(stmt);
+
+                // Recursively reconstruct the binary expression, in case the elements are themselves arrays.
+                let (expression, stmts) = self.reconstruct_binary(BinaryExpression {
+                    op,
+                    left: Box::new(Expression::Identifier(first)),
+                    right: Box::new(Expression::Identifier(second)),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the binary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, array.element_type().clone());
+                        id
+                    },
+                });
+
+                // Accumulate any statements generated.
+                statements.extend(stmts);
+
+                expression
+            })
+            .collect();
+
+        // Construct the array expression.
+        let (expr, stmts) = self.reconstruct_array(ArrayExpression {
+            elements,
+            span: Default::default(),
+            id: {
+                // Create a node ID for the array expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Array(array.clone()));
+                id
+            },
+        });
+
+        // Accumulate any statements generated.
+        statements.extend(stmts);
+
+        // Create a new assignment statement for the array expression.
+        let (identifier, statement) = self.unique_simple_assign_statement(expr);
+
+        statements.push(statement);
+
+        (Expression::Identifier(identifier), statements)
+    }
+
+    /// Flattens a unary operation over an array into a new array of element-wise operations.
+    /// For example, `!a`, where `a` is a `[bool; 2]`, is flattened into the following:
+    /// ```leo
+    /// let var$0 = a[0u32];
+    /// let var$1 = !var$0;
+    /// let var$2 = a[1u32];
+    /// let var$3 = !var$2;
+    /// let var$4 = [var$1, var$3];
+    /// var$4
+    /// ```
+    pub(crate) fn unary_array(
+        &mut self,
+        array: &ArrayType,
+        op: UnaryOperation,
+        receiver: &Identifier,
+    ) -> (Expression, Vec<Statement>) {
+        // Initialize a vector to accumulate any statements generated.
+        let mut statements = Vec::new();
+        // For each array element, construct a new unary expression.
+        let elements = (0..array.length())
+            .map(|i| {
+                // Create an assignment statement for the element of the receiver.
+                let (receiver, stmt) = self.unique_array_element_assign_statement(array, receiver, i);
+                statements.push(stmt);
+
+                // Recursively reconstruct the unary expression, in case the elements are themselves arrays.
+                let (expression, stmts) = self.reconstruct_unary(UnaryExpression {
+                    op,
+                    receiver: Box::new(Expression::Identifier(receiver)),
+                    span: Default::default(),
+                    id: {
+                        // Create a new node ID for the unary expression.
+                        let id = self.node_builder.next_id();
+                        // Set the type of the node ID.
+                        self.type_table.insert(id, array.element_type().clone());
+                        id
+                    },
+                });
+
+                // Accumulate any statements generated.
+                statements.extend(stmts);
+
+                expression
+            })
+            .collect();
+
+        // Construct the array expression.
+        let (expr, stmts) = self.reconstruct_array(ArrayExpression {
+            elements,
+            span: Default::default(),
+            id: {
+                // Create a node ID for the array expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Array(array.clone()));
+                id
+            },
+        });
+
+        // Accumulate any statements generated.
+        statements.extend(stmts);
+
+        // Create a new assignment statement for the array expression.
+        let (identifier, statement) = self.unique_simple_assign_statement(expr);
+
+        statements.push(statement);
+
+        (Expression::Identifier(identifier), statements)
+    }
+
+    /// Lowers a saturating operation over two unsigned integers into wrapping arithmetic and a ternary expression.
+    /// This is necessary because Aleo instructions do not support saturating arithmetic.
+    /// For example, `a.add_saturating(b)`, where `a` and `b` are both `u8`s, is flattened into the following:
+    /// ```leo
+    /// let var$0 = a.add_wrapped(b);
+    /// let var$1 = var$0 < a;
+    /// var$1 ? 255u8 : var$0
+    /// ```
+    /// Similarly, `a.sub_saturating(b)` is flattened into the following:
+    /// ```leo
+    /// let var$0 = a.sub_wrapped(b);
+    /// let var$1 = a < b;
+    /// var$1 ? 0u8 : var$0
+    /// ```
+    pub(crate) fn saturating_binary(
+        &mut self,
+        integer_type: IntegerType,
+        op: BinaryOperation,
+        first: &Identifier,
+        second: &Identifier,
+    ) -> (Expression, Vec<Statement>) {
+        // Determine the wrapping operation and the value to saturate to.
+        let (wrapping_op, bound) = match (op, integer_type) {
+            (BinaryOperation::SubSaturating, _) => (BinaryOperation::SubWrapped, "0".to_string()),
+            (BinaryOperation::AddSaturating, IntegerType::U8) => (BinaryOperation::AddWrapped, u8::MAX.to_string()),
+            (BinaryOperation::AddSaturating, IntegerType::U16) => (BinaryOperation::AddWrapped, u16::MAX.to_string()),
+            (BinaryOperation::AddSaturating, IntegerType::U32) => (BinaryOperation::AddWrapped, u32::MAX.to_string()),
+            (BinaryOperation::AddSaturating, IntegerType::U64) => (BinaryOperation::AddWrapped, u64::MAX.to_string()),
+            (BinaryOperation::AddSaturating, IntegerType::U128) => (BinaryOperation::AddWrapped, u128::MAX.to_string()),
+            _ => unreachable!("Type checking guarantees that saturating operations are over unsigned integers."),
+        };
+
+        // Construct an assignment statement for the wrapped result.
+        let (wrapped, wrapped_statement) = self.unique_simple_assign_statement(Expression::Binary(BinaryExpression {
+            left: Box::new(Expression::Identifier(*first)),
+            right: Box::new(Expression::Identifier(*second)),
+            op: wrapping_op,
+            span: Default::default(),
+            id: {
+                // Create a new node ID for the binary expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Integer(integer_type));
+                id
+            },
+        }));
+
+        // Construct an assignment statement for whether the operation overflowed or underflowed.
+        let (left, right) = match op {
+            BinaryOperation::AddSaturating => (wrapped, *first),
+            _ => (*first, *second),
+        };
+        let (overflow, overflow_statement) =
+            self.unique_simple_assign_statement(Expression::Binary(BinaryExpression {
+                left: Box::new(Expression::Identifier(left)),
+                right: Box::new(Expression::Identifier(right)),
+                op: BinaryOperation::Lt,
+                span: Default::default(),
+                id: {
+                    // Create a new node ID for the binary expression.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Boolean);
+                    id
+                },
+            }));
+
+        // Select the bound if the operation overflowed or underflowed, and the wrapped result otherwise.
+        let expression = Expression::Ternary(TernaryExpression {
+            condition: Box::new(Expression::Identifier(overflow)),
+            if_true: Box::new(Expression::Literal(Literal::Integer(integer_type, bound, Default::default(), {
+                // Create a new node ID for the literal.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Integer(integer_type));
+                id
+            }))),
+            if_false: Box::new(Expression::Identifier(wrapped)),
+            span: Default::default(),
+            id: {
+                // Create a new node ID for the ternary expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Integer(integer_type));
+                id
+            },
+        });
+
+        (expression, vec![wrapped_statement, overflow_statement])
+    }
+
+    /// Constructs the concatenation of two arrays with the same element type.
+    /// For example, `concat(a, b)`, where `a` is a `[u8; 1]` and `b` is a `[u8; 2]`, is flattened into the following:
+    /// ```leo
+    /// let var$0 = a[0u32];
+    /// let var$1 = b[0u32];
+    /// let var$2 = b[1u32];
+    /// let var$3 = [var$0, var$1, var$2];
+    /// var$3
+    /// ```
+    pub(crate) fn concat_arrays(
+        &mut self,
+        output: &ArrayType,
+        first: &Identifier,
+        second: &Identifier,
+    ) -> (Expression, Vec<Statement>) {
+        // Initialize a vector to accumulate any statements generated.
+        let mut statements = Vec::new();
+        // Access each element of the first array, followed by each element of the second array.
+        let mut elements = Vec::with_capacity(output.length());
+        for array in [first, second] {
+            let array_type = match self.type_table.get(&array.id()) {
+                Some(Type::Array(array_type)) => array_type,
+                _ => unreachable!("Type checking guarantees that the arguments of `concat` are arrays."),
+            };
+            for i in 0..array_type.length() {
+                let (element, stmt) = self.unique_array_element_assign_statement(&array_type, array, i);
+                statements.push(stmt);
+                elements.push(Expression::Identifier(element));
+            }
+        }
+
+        // Construct the array expression.
+        let (expr, stmts) = self.reconstruct_array(ArrayExpression {
+            elements,
+            span: Default::default(),
+            id: {
+                // Create a node ID for the array expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, Type::Array(output.clone()));
+                id
+            },
+        });
+
+        // Accumulate any statements generated.
+        statements.extend(stmts);
+
+        // Create a new assignment statement for the array expression.
+        let (identifier, statement) = self.unique_simple_assign_statement(expr);
+
+        statements.push(statement);
+
+        (Expression::Identifier(identifier), statements)
+    }
+
+    /// Folds the elements of a boolean array with `operation`, which is either `||` or `&&`.
+    /// For example, `any(a)`, where `a` is a `[bool; 3]`, is flattened into the following:
+    /// ```leo
+    /// let var$0 = a[0u32];
+    /// let var$1 = a[1u32];
+    /// let var$2 = var$0 || var$1;
+    /// let var$3 = a[2u32];
+    /// let var$4 = var$2 || var$3;
+    /// var$4
+    /// ```
+    pub(crate) fn fold_boolean_array(
+        &mut self,
+        array_type: &ArrayType,
+        array: &Identifier,
+        operation: BinaryOperation,
+    ) -> (Expression, Vec<Statement>) {
+        // Initialize a vector to accumulate any statements generated.
+        let mut statements = Vec::new();
+        // Combine each element of the array with the result of the elements before it.
+        let mut result: Option<Identifier> = None;
+        for i in 0..array_type.length() {
+            let (element, stmt) = self.unique_array_element_assign_statement(array_type, array, i);
+            statements.push(stmt);
+            result = Some(match result {
+                None => element,
+                Some(accumulator) => {
+                    let (identifier, statement) =
+                        self.unique_simple_assign_statement(Expression::Binary(BinaryExpression {
+                            left: Box::new(Expression::Identifier(accumulator)),
+                            right: Box::new(Expression::Identifier(element)),
+                            op: operation,
+                            span: Default::default(),
+                            id: {
+                                // Create a new node ID for the binary expression.
+                                let id = self.node_builder.next_id();
+                                // Set the type of the node ID.
+                                self.type_table.insert(id, Type::Boolean);
+                                id
+                            },
+                        }));
+                    statements.push(statement);
+                    identifier
+                }
+            });
+        }
+
+        match result {
+            Some(identifier) => (Expression::Identifier(identifier), statements),
+            None => unreachable!("Type checking guarantees that arrays are not empty."),
+        }
+    }
+
+    /// Creates a new assignment statement for the `index`-th element of the `array` variable.
+    fn unique_array_element_assign_statement(
+        &mut self,
+        array_type: &ArrayType,
+        array: &Identifier,
+        index: usize,
+    ) -> (Identifier, Statement) {
+        self.unique_simple_assign_statement(Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(Expression::Identifier(*array)),
+            index: Box::new(Expression::Literal(Literal::Integer(
+                IntegerType::U32,
+                index.to_string(),
+                Default::default(),
+                {
+                    // Create a new node ID for the literal.
+                    let id = self.node_builder.next_id();
+                    // Set the type of the node ID.
+                    self.type_table.insert(id, Type::Integer(IntegerType::U32));
+                    id
+                },
+            ))),
+            span: Default::default(),
+            id: {
+                // Create a new node ID for the access expression.
+                let id = self.node_builder.next_id();
+                // Set the type of the node ID.
+                self.type_table.insert(id, array_type.element_type().clone());
+                id
+            },
+        })))
+    }
+
     pub(crate) fn ternary_array(
         &mut self,
         array: &ArrayType,
@@ -458,6 +891,7 @@ impl<'a> Flattener<'a> {
         let (expr, stmts) = self.reconstruct_struct_init(StructExpression {
             name: struct_.identifier,
             members,
+            base: None,
             span: Default::default(),
             id: {
                 // Create a new node ID for the struct expression.
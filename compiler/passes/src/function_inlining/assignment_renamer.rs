@@ -103,6 +103,7 @@ impl ExpressionReconstructor for AssignmentRenamer<'_> {
                         id: member.id,
                     })
                     .collect(),
+                base: None,
                 span: input.span,
                 id: input.id,
             }),
@@ -14,9 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Assigner, AssignmentRenamer, CallGraph, TypeTable};
+use crate::{Assigner, AssignmentRenamer, CallGraph, GuardTable, TypeTable};
 
-use leo_ast::{Function, NodeBuilder};
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    BinaryExpression,
+    BinaryOperation,
+    Expression,
+    Function,
+    NodeBuilder,
+    NodeID,
+    Statement,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+};
 use leo_span::Symbol;
 
 pub struct FunctionInliner<'a> {
@@ -28,6 +41,8 @@ pub struct FunctionInliner<'a> {
     pub(crate) assignment_renamer: AssignmentRenamer<'a>,
     /// A mapping between node IDs and their types.
     pub(crate) type_table: &'a TypeTable,
+    /// A mapping between the node IDs of calls to inline functions and the guards they are executed under.
+    pub(crate) guard_table: &'a GuardTable,
     /// A map of reconstructed functions in the current program scope.
     pub(crate) reconstructed_functions: Vec<(Symbol, Function)>,
 }
@@ -39,6 +54,7 @@ impl<'a> FunctionInliner<'a> {
         call_graph: &'a CallGraph,
         assigner: &'a Assigner,
         type_table: &'a TypeTable,
+        guard_table: &'a GuardTable,
     ) -> Self {
         Self {
             node_builder,
@@ -46,6 +62,46 @@ impl<'a> FunctionInliner<'a> {
             assignment_renamer: AssignmentRenamer::new(assigner),
             reconstructed_functions: Default::default(),
             type_table,
+            guard_table,
         }
     }
+
+    /// Guards an inlined assert statement, so that it is only checked if `guard` holds.
+    /// For example, `assert_eq(a, b)` is rewritten to `assert(!guard || a == b)`.
+    pub(crate) fn guard_assert(&mut self, guard: &Expression, input: AssertStatement) -> Statement {
+        let expression = match input.variant {
+            AssertVariant::Assert(expression) => expression,
+            AssertVariant::AssertEq(left, right) => self.boolean_binary(BinaryOperation::Eq, left, right),
+            AssertVariant::AssertNeq(left, right) => self.boolean_binary(BinaryOperation::Neq, left, right),
+        };
+        let not_guard = Expression::Unary(UnaryExpression {
+            op: UnaryOperation::Not,
+            receiver: Box::new(guard.clone()),
+            span: Default::default(),
+            id: self.boolean_id(),
+        });
+        Statement::Assert(AssertStatement {
+            variant: AssertVariant::Assert(self.boolean_binary(BinaryOperation::Or, not_guard, expression)),
+            span: input.span,
+            id: input.id,
+        })
+    }
+
+    /// Constructs a binary expression of type `bool`.
+    fn boolean_binary(&mut self, op: BinaryOperation, left: Expression, right: Expression) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: Default::default(),
+            id: self.boolean_id(),
+        })
+    }
+
+    /// Creates a new node ID, with type `bool` in the type table.
+    fn boolean_id(&mut self) -> NodeID {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        id
+    }
 }
@@ -101,6 +101,17 @@ impl ExpressionReconstructor for FunctionInliner<'_> {
                     }
                 };
 
+                // If the call is only executed under a guard, then the inlined asserts are only checked if the guard holds.
+                if let Some(guard) = self.guard_table.get(&input.id) {
+                    inlined_statements = inlined_statements
+                        .into_iter()
+                        .map(|statement| match statement {
+                            Statement::Assert(assert) => self.guard_assert(&guard, assert),
+                            statement => statement,
+                        })
+                        .collect();
+                }
+
                 (result, inlined_statements)
             }
         }
@@ -21,7 +21,7 @@ pub mod unroller;
 pub use unroller::*;
 
 pub mod unroll_expression;
-pub use unroll_expression::*;
+pub(crate) use unroll_expression::*;
 
 pub mod unroll_program;
 pub use unroll_program::*;
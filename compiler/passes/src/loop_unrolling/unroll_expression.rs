@@ -17,6 +17,8 @@
 use leo_ast::*;
 use leo_errors::LoopUnrollerError;
 
+use std::str::FromStr;
+
 use crate::Unroller;
 
 impl ExpressionReconstructor for Unroller<'_> {
@@ -24,7 +26,32 @@ impl ExpressionReconstructor for Unroller<'_> {
 
     fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
         // Reconstruct the index.
-        let index = self.reconstruct_expression(*input.index).0;
+        let index = match self.reconstruct_expression(*input.index.clone()).0 {
+            // Normalize a negative constant index, so that `a[-1i8]` refers to the last element of `a`.
+            Expression::Literal(Literal::Integer(type_, string, span, id)) if string.starts_with('-') => {
+                match self.type_table.get(&input.array.id()) {
+                    Some(Type::Array(array_type)) => {
+                        // Integer literals have been checked against their type, so the value fits in an `i128`.
+                        let value = string.replace('_', "").parse::<i128>().unwrap();
+                        let normalized = array_type.length() as i128 + value;
+                        if normalized < 0 {
+                            self.emit_err(LoopUnrollerError::array_index_out_of_bounds(
+                                &string,
+                                array_type.length(),
+                                span,
+                            ));
+                            return (Expression::Access(AccessExpression::Array(input)), Default::default());
+                        }
+                        // The normalized index is a `u32` literal, so it gets a new node ID with the matching type.
+                        let id = self.node_builder.next_id();
+                        self.type_table.insert(id, Type::Integer(IntegerType::U32));
+                        Expression::Literal(Literal::Integer(IntegerType::U32, normalized.to_string(), span, id))
+                    }
+                    _ => Expression::Literal(Literal::Integer(type_, string, span, id)),
+                }
+            }
+            index => index,
+        };
         // If the index is not a literal, then emit an error.
         if !matches!(index, Expression::Literal(_)) {
             self.emit_err(LoopUnrollerError::variable_array_access(input.span));
@@ -41,6 +68,82 @@ impl ExpressionReconstructor for Unroller<'_> {
         )
     }
 
+    /// Folds a cast of an integer literal to an integer type into a literal of that type.
+    /// For example, `5u32 as u64` is reconstructed as `5u64`.
+    /// A literal that does not fit in the target type is left as a cast.
+    /// Type checking reports the casts of integer literals that are out of range.
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        let expression = self.reconstruct_expression(*input.expression).0;
+
+        if let (Expression::Literal(Literal::Integer(_, string, ..)), Type::Integer(target)) =
+            (&expression, &input.type_)
+        {
+            if let Some(digits) = cast_integer_digits(*target, string) {
+                return (
+                    Expression::Literal(Literal::Integer(*target, digits, input.span, input.id)),
+                    Default::default(),
+                );
+            }
+        }
+
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(expression),
+                type_: input.type_,
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Replaces a count given by a constant with the value of the constant.
+    fn reconstruct_repeat(&mut self, input: RepeatExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Repeat(RepeatExpression {
+                element: Box::new(self.reconstruct_expression(*input.element).0),
+                count: ArrayLength::Literal(NonNegativeNumber::from(self.array_length(&input.count))),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Folds `&&` and `||` expressions with a boolean literal operand.
+    /// For example, `false && x` is reconstructed as `false` and `true || x` is reconstructed as `true`.
+    /// Since both operands are always evaluated, this is only done if the dropped operand is a literal or an identifier.
+    /// Otherwise, e.g. in `false && f(x)`, an assertion in `f` or an overflow in `x + 1u8` would no longer halt.
+    /// A boolean literal that does not determine the result is dropped, e.g. `x && true` is reconstructed as `x`.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let left = self.reconstruct_expression(*input.left).0;
+        let right = self.reconstruct_expression(*input.right).0;
+
+        let right_is_pure = matches!(right, Expression::Literal(_) | Expression::Identifier(_));
+        match (input.op, &left, &right) {
+            (BinaryOperation::And, Expression::Literal(Literal::Boolean(false, ..)), _)
+            | (BinaryOperation::Or, Expression::Literal(Literal::Boolean(true, ..)), _)
+                if right_is_pure =>
+            {
+                (left, Default::default())
+            }
+            (BinaryOperation::And, _, Expression::Literal(Literal::Boolean(true, ..)))
+            | (BinaryOperation::Or, _, Expression::Literal(Literal::Boolean(false, ..))) => (left, Default::default()),
+            (BinaryOperation::And, Expression::Literal(Literal::Boolean(true, ..)), _)
+            | (BinaryOperation::Or, Expression::Literal(Literal::Boolean(false, ..)), _) => (right, Default::default()),
+            _ => (
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op: input.op,
+                    span: input.span,
+                    id: input.id,
+                }),
+                Default::default(),
+            ),
+        }
+    }
+
     fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
         // Substitute the identifier with the constant value if it is a constant.
         if let Some(expr) = self.constant_propagation_table.borrow().lookup_constant(input.name) {
@@ -49,3 +152,88 @@ impl ExpressionReconstructor for Unroller<'_> {
         (Expression::Identifier(input), Default::default())
     }
 }
+
+/// Evaluates `+`, `-`, and `*` over integer literals in a loop bound, after constants have been propagated.
+/// For example, `N + 1u32` with `const N: u32 = 4u32;` is evaluated to `5u32`.
+/// A subexpression that overflows, or uses any other operator, is left unevaluated.
+pub(crate) fn evaluate_loop_bound(bound: Expression) -> Expression {
+    let Expression::Binary(binary) = bound else {
+        return bound;
+    };
+
+    let left = evaluate_loop_bound(*binary.left);
+    let right = evaluate_loop_bound(*binary.right);
+
+    if let (
+        Expression::Literal(Literal::Integer(integer_type, left_digits, ..)),
+        Expression::Literal(Literal::Integer(_, right_digits, ..)),
+    ) = (&left, &right)
+    {
+        if let Some(digits) = integer_arithmetic_digits(binary.op, *integer_type, left_digits, right_digits) {
+            return Expression::Literal(Literal::Integer(*integer_type, digits, binary.span, binary.id));
+        }
+    }
+
+    Expression::Binary(BinaryExpression {
+        left: Box::new(left),
+        right: Box::new(right),
+        op: binary.op,
+        span: binary.span,
+        id: binary.id,
+    })
+}
+
+/// Returns the digits of `left op right` as a literal of type `integer_type`, if `op` is `+`, `-`, or `*` and the result fits in it.
+fn integer_arithmetic_digits(
+    op: BinaryOperation,
+    integer_type: IntegerType,
+    left: &str,
+    right: &str,
+) -> Option<String> {
+    let (left, right) = (left.replace('_', ""), right.replace('_', ""));
+    let result = match integer_type {
+        IntegerType::U128 => {
+            let (left, right) = (left.parse::<u128>().ok()?, right.parse::<u128>().ok()?);
+            match op {
+                BinaryOperation::Add => left.checked_add(right),
+                BinaryOperation::Sub => left.checked_sub(right),
+                BinaryOperation::Mul => left.checked_mul(right),
+                _ => None,
+            }?
+            .to_string()
+        }
+        _ => {
+            let (left, right) = (left.parse::<i128>().ok()?, right.parse::<i128>().ok()?);
+            match op {
+                BinaryOperation::Add => left.checked_add(right),
+                BinaryOperation::Sub => left.checked_sub(right),
+                BinaryOperation::Mul => left.checked_mul(right),
+                _ => None,
+            }?
+            .to_string()
+        }
+    };
+    // Check that the result is in range for `integer_type`.
+    cast_integer_digits(integer_type, &result)
+}
+
+/// Returns the digits of an integer literal's value as a literal of type `integer_type`, if the value fits in it.
+fn cast_integer_digits(integer_type: IntegerType, string: &str) -> Option<String> {
+    fn parse<T: FromStr + ToString>(digits: &str) -> Option<String> {
+        digits.parse::<T>().ok().map(|value| value.to_string())
+    }
+
+    let digits = string.replace('_', "");
+    match integer_type {
+        IntegerType::U8 => parse::<u8>(&digits),
+        IntegerType::U16 => parse::<u16>(&digits),
+        IntegerType::U32 => parse::<u32>(&digits),
+        IntegerType::U64 => parse::<u64>(&digits),
+        IntegerType::U128 => parse::<u128>(&digits),
+        IntegerType::I8 => parse::<i8>(&digits),
+        IntegerType::I16 => parse::<i16>(&digits),
+        IntegerType::I32 => parse::<i32>(&digits),
+        IntegerType::I64 => parse::<i64>(&digits),
+        IntegerType::I128 => parse::<i128>(&digits),
+    }
+}
@@ -26,8 +26,8 @@ impl ProgramReconstructor for Unroller<'_> {
         });
         ProgramScope {
             program_id: input.program_id,
-            structs: input.structs,
-            mappings: input.mappings,
+            structs: input.structs.into_iter().map(|(i, s)| (i, self.reconstruct_struct(s))).collect(),
+            mappings: input.mappings.into_iter().map(|(i, m)| (i, self.reconstruct_mapping(m))).collect(),
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
             span: input.span,
@@ -57,9 +57,9 @@ impl ProgramReconstructor for Unroller<'_> {
 
             Finalize {
                 identifier: finalize.identifier,
-                input: finalize.input,
-                output: finalize.output,
-                output_type: finalize.output_type,
+                input: finalize.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+                output_type: self.resolve_type(finalize.output_type),
                 block,
                 span: finalize.span,
                 id: finalize.id,
@@ -71,9 +71,9 @@ impl ProgramReconstructor for Unroller<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
-            input: function.input,
-            output: function.output,
-            output_type: function.output_type,
+            input: function.input.into_iter().map(|input| self.resolve_input(input)).collect(),
+            output: function.output.into_iter().map(|output| self.resolve_output(output)).collect(),
+            output_type: self.resolve_type(function.output_type),
             block,
             finalize,
             span: function.span,
@@ -85,4 +85,23 @@ impl ProgramReconstructor for Unroller<'_> {
 
         reconstructed_function
     }
+
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.resolve_type(member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.resolve_type(input.key_type),
+            value_type: self.resolve_type(input.value_type),
+            ..input
+        }
+    }
 }
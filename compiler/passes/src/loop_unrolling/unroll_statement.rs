@@ -19,7 +19,7 @@ use leo_ast::{Expression::Literal, Type::Integer, *};
 use leo_errors::loop_unroller::LoopUnrollerError;
 use leo_span::{Span, Symbol};
 
-use crate::{unroller::Unroller, VariableSymbol, VariableType};
+use crate::{evaluate_loop_bound, unroller::Unroller, VariableSymbol, VariableType};
 
 impl StatementReconstructor for Unroller<'_> {
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
@@ -65,7 +65,7 @@ impl StatementReconstructor for Unroller<'_> {
         (
             Statement::Const(ConstDeclaration {
                 place: input.place,
-                type_: input.type_,
+                type_: self.resolve_type(input.type_),
                 value: reconstructed_value_expression,
                 span: input.span,
                 id: input.id,
@@ -75,12 +75,16 @@ impl StatementReconstructor for Unroller<'_> {
     }
 
     fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        // Resolve the array lengths in the type of the definition.
+        let type_ = self.resolve_type(input.type_);
+
         // Helper function to add  variables to symbol table
         let insert_variable = |symbol: Symbol, type_: Type, span: Span| {
             if let Err(err) = self.symbol_table.borrow_mut().insert_variable(symbol, VariableSymbol {
                 type_,
                 span,
                 declaration: VariableType::Mut,
+                value: None,
             }) {
                 self.handler.emit_err(err);
             }
@@ -90,10 +94,10 @@ impl StatementReconstructor for Unroller<'_> {
         if self.is_unrolling {
             match &input.place {
                 Expression::Identifier(identifier) => {
-                    insert_variable(identifier.name, input.type_.clone(), input.span);
+                    insert_variable(identifier.name, type_.clone(), input.span);
                 }
                 Expression::Tuple(tuple_expression) => {
-                    let tuple_type = match input.type_ {
+                    let tuple_type = match type_ {
                         Type::Tuple(ref tuple_type) => tuple_type,
                         _ => unreachable!(
                             "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
@@ -104,7 +108,7 @@ impl StatementReconstructor for Unroller<'_> {
                             Expression::Identifier(identifier) => identifier,
                             _ => unreachable!("Type checking guarantees that if the lhs is a tuple, all of its elements are identifiers.")
                         };
-                        insert_variable(identifier.name, input.type_.clone(), input.span);
+                        insert_variable(identifier.name, type_.clone(), input.span);
                     });
                 }
                 _ => unreachable!(
@@ -118,7 +122,7 @@ impl StatementReconstructor for Unroller<'_> {
             Statement::Definition(DefinitionStatement {
                 declaration_type: input.declaration_type,
                 place: input.place,
-                type_: input.type_,
+                type_,
                 value: self.reconstruct_expression(input.value).0,
                 span: input.span,
                 id: input.id,
@@ -128,9 +132,9 @@ impl StatementReconstructor for Unroller<'_> {
     }
 
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
-        // Reconstruct the bound expressions
-        let (new_start, _) = self.reconstruct_expression(input.start);
-        let (new_stop, _) = self.reconstruct_expression(input.stop);
+        // Reconstruct the bound expressions, substituting constants and evaluating arithmetic on them.
+        let new_start = evaluate_loop_bound(self.reconstruct_expression(input.start).0);
+        let new_stop = evaluate_loop_bound(self.reconstruct_expression(input.stop).0);
 
         // Convert into values
         match (new_start.clone(), new_stop.clone()) {
@@ -138,7 +142,15 @@ impl StatementReconstructor for Unroller<'_> {
                 input.start_value.replace(Some(Value::try_from(&start_lit).unwrap()));
                 input.stop_value.replace(Some(Value::try_from(&stop_lit).unwrap()));
             }
-            _ => unreachable!("Type checking guarantees that the loop bounds are literals."),
+            // Type checking guarantees that the bounds are built from literals and constants.
+            // So, a bound is only left unevaluated if its arithmetic overflows.
+            (start, stop) => {
+                [start, stop]
+                    .iter()
+                    .filter(|bound| !matches!(bound, Literal(_)))
+                    .for_each(|bound| self.emit_err(LoopUnrollerError::loop_bound_overflow(bound.span())));
+                return (Statement::dummy(input.span, input.id), Default::default());
+            }
         };
 
         // Ensure loop bounds are increasing. This cannot be done in the type checker because constant propagation occurs in this pass.
@@ -15,12 +15,17 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
+    ArrayLength,
     Block,
     Expression,
+    FunctionInput,
+    FunctionOutput,
+    Input,
     IntegerType,
     IterationStatement,
     Literal,
     NodeBuilder,
+    Output,
     Statement,
     StatementReconstructor,
     Type,
@@ -114,6 +119,42 @@ impl<'a> Unroller<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Returns the value of an array length, looking up the value of the constant if it is given by name.
+    pub(crate) fn array_length(&self, length: &ArrayLength) -> usize {
+        match length {
+            ArrayLength::Literal(length) => length.value(),
+            ArrayLength::Constant(name) => match self.constant_propagation_table.borrow().lookup_constant(name.name) {
+                Some(Expression::Literal(Literal::Integer(IntegerType::U32, value, ..))) => {
+                    value.replace('_', "").parse().unwrap()
+                }
+                _ => unreachable!("Type checking guarantees that an array length is a `u32` constant in scope."),
+            },
+        }
+    }
+
+    /// Returns the type with each array length given by a constant replaced with the value of the constant.
+    pub(crate) fn resolve_type(&self, type_: Type) -> Type {
+        type_.resolve_array_lengths(&|name| Some(self.array_length(&ArrayLength::Constant(*name))))
+    }
+
+    /// Returns the function input with the array lengths in its type resolved.
+    pub(crate) fn resolve_input(&self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput { type_: self.resolve_type(input.type_), ..input }),
+            Input::External(_) => input,
+        }
+    }
+
+    /// Returns the function output with the array lengths in its type resolved.
+    pub(crate) fn resolve_output(&self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => {
+                Output::Internal(FunctionOutput { type_: self.resolve_type(output.type_), ..output })
+            }
+            Output::External(_) => output,
+        }
+    }
+
     /// Unrolls an IterationStatement.
     pub(crate) fn unroll_iteration_statement<I: LoopBound>(&mut self, input: IterationStatement) -> Statement {
         let start: Value = input.start_value.borrow().as_ref().expect("Failed to get start value").clone();
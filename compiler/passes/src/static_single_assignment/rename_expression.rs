@@ -28,7 +28,10 @@ use leo_ast::{
     ExpressionConsumer,
     Identifier,
     Literal,
+    Member,
     MemberAccess,
+    Node,
+    RepeatExpression,
     Statement,
     Struct,
     StructExpression,
@@ -176,7 +179,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         let mut statements = Vec::new();
 
         // Process the arguments, accumulating any statements produced.
-        let arguments = input
+        let mut arguments: Vec<Expression> = input
             .arguments
             .into_iter()
             .map(|argument| {
@@ -186,6 +189,23 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             })
             .collect();
 
+        // Pass the default value of each omitted parameter.
+        // Note that type checking guarantees that the omitted parameters have defaults, and that these are literals.
+        if let Expression::Identifier(function) = &*input.function {
+            if let Some(func) = self.symbol_table.lookup_fn_symbol(function.name) {
+                for omitted in func.input.iter().skip(arguments.len()) {
+                    if let Some(default) = omitted.default_value() {
+                        // Each copy of the default is a new node.
+                        let mut default = default.clone();
+                        let id = self.node_builder.next_id();
+                        default.set_id(id);
+                        self.type_table.insert(id, omitted.type_());
+                        arguments.push(default);
+                    }
+                }
+            }
+        }
+
         // Construct and accumulate a new assignment statement for the call expression.
         let (place, statement) = self.unique_simple_assign_statement(Expression::Call(CallExpression {
             // Note that we do not rename the function name.
@@ -218,6 +238,36 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         (Expression::Identifier(place), statements)
     }
 
+    /// Consumes an array repeat expression, accumulating any statements that are generated.
+    /// The element is evaluated once, and the array is constructed from copies of the resulting variable.
+    fn consume_repeat(&mut self, input: RepeatExpression) -> Self::Output {
+        // Reconstruct the element of the repeat expression.
+        let (element, mut statements) = self.consume_expression(*input.element);
+
+        // Each copy of the element gets its own node ID.
+        let elements = (0..input.count.value())
+            .map(|_| {
+                let mut copy = element.clone();
+                let id = self.node_builder.next_id();
+                if let Some(type_) = self.type_table.get(&element.id()) {
+                    self.type_table.insert(id, type_);
+                }
+                copy.set_id(id);
+                copy
+            })
+            .collect();
+
+        // Construct and accumulate a new assignment statement for the resulting array expression.
+        let (place, statement) = self.unique_simple_assign_statement(Expression::Array(ArrayExpression {
+            elements,
+            span: input.span,
+            id: input.id,
+        }));
+        statements.push(statement);
+
+        (Expression::Identifier(place), statements)
+    }
+
     /// Consumes a struct initialization expression with renamed variables, accumulating any statements that are generated.
     fn consume_struct_init(&mut self, input: StructExpression) -> Self::Output {
         let mut statements = Vec::new();
@@ -248,6 +298,13 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             })
             .collect();
 
+        // Process the base struct, if any, accumulating any statements produced.
+        let base = input.base.map(|base| {
+            let (base, mut stmts) = self.consume_expression(*base);
+            statements.append(&mut stmts);
+            base
+        });
+
         // Reorder the members to match that of the struct definition.
 
         // Lookup the struct definition.
@@ -261,12 +318,45 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         let mut member_map: IndexMap<Symbol, StructVariableInitializer> =
             members.into_iter().map(|member| (member.identifier.name, member)).collect();
 
+        // Removes the initializer of `member` from the init expression.
+        // If there is none, the member is read from the base struct, i.e. `<base>.<member>`.
+        let mut take_member = |member: &Member| {
+            member_map.remove(&member.identifier.name).unwrap_or_else(|| {
+                // Type checking guarantees that every member without an initializer is read from a base.
+                let base = match &base {
+                    Some(base) => base,
+                    None => unreachable!("A struct expression without a base must initialize every member."),
+                };
+                // Each read of the base is a copy with a fresh node ID, so that node IDs remain unique.
+                let mut inner = base.clone();
+                let inner_id = self.node_builder.next_id();
+                if let Some(type_) = self.type_table.get(&base.id()) {
+                    self.type_table.insert(inner_id, type_);
+                }
+                inner.set_id(inner_id);
+
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, member.type_.clone());
+                StructVariableInitializer {
+                    identifier: member.identifier,
+                    expression: Some(Expression::Access(AccessExpression::Member(MemberAccess {
+                        inner: Box::new(inner),
+                        name: member.identifier,
+                        span: Default::default(),
+                        id,
+                    }))),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                }
+            })
+        };
+
         // If we are initializing a record, add the `owner` first.
         // Note that type checking guarantees that the above fields exist.
         if struct_definition.is_record {
             // Add the `owner` field.
-            // Note that the `unwrap` is safe, since type checking guarantees that the member exists.
-            reordered_members.push(member_map.remove(&sym::owner).unwrap());
+            let owner = struct_definition.members.iter().find(|member| member.identifier.name == sym::owner).unwrap();
+            reordered_members.push(take_member(owner));
         }
 
         // For each member of the struct definition, push the corresponding member of the init expression.
@@ -274,8 +364,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             // If the member is part of a record and it is `owner` then we have already added it.
             if !(struct_definition.is_record && matches!(member.identifier.name, sym::owner)) {
                 // Lookup and push the member of the init expression.
-                // Note that the `unwrap` is safe, since type checking guarantees that the member exists.
-                reordered_members.push(member_map.remove(&member.identifier.name).unwrap());
+                reordered_members.push(take_member(member));
             }
         }
 
@@ -284,6 +373,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
             name: input.name,
             span: input.span,
             members: reordered_members,
+            base: None,
             id: input.id,
         }));
         statements.push(statement);
@@ -62,6 +62,7 @@ impl<'a> ProgramVisitor<'a> for SymbolTableCreator<'a> {
             }),
             span: input.span,
             declaration: VariableType::Mut,
+            value: None,
         }) {
             self.handler.emit_err(err);
         }
@@ -21,7 +21,7 @@ use leo_errors::{emitter::Handler, TypeCheckerError};
 use leo_span::{sym, Span};
 
 use itertools::Itertools;
-use snarkvm_console::network::{Network, Testnet3};
+use snarkvm_console::network::{Environment, Network, Testnet3};
 use std::str::FromStr;
 
 fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<Type>) -> Option<Type> {
@@ -38,6 +38,70 @@ fn return_incorrect_type(t1: Option<Type>, t2: Option<Type>, expected: &Option<T
     }
 }
 
+/// The reasons the digits of a field literal may fail to parse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldParseError {
+    /// There are no digits, e.g. `-` or `_`.
+    Empty,
+    /// There is a character other than a decimal digit or an underscore, after an optional leading `-`.
+    Malformed,
+    /// The value is not less than the field modulus.
+    OutOfModulus,
+}
+
+/// Parses the digits of a field literal, e.g. `1_000` or `-5`, into a field element.
+/// Underscores and leading zeros are ignored, and a leading `-` negates the value.
+/// Never panics: returns an error if the digits are empty or not decimal, or if the value is not less than the field
+/// modulus, instead of silently reducing it as snarkVM would.
+pub fn parse_field_literal(string: &str) -> Result<<Testnet3 as Environment>::Field, FieldParseError> {
+    let (negative, digits) = match string.strip_prefix('-') {
+        Some(digits) => (true, digits.replace('_', "")),
+        None => (false, string.replace('_', "")),
+    };
+    if digits.is_empty() {
+        return Err(FieldParseError::Empty);
+    }
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(FieldParseError::Malformed);
+    }
+    let digits = match digits.trim_start_matches('0') {
+        "" => "0",
+        digits => digits,
+    };
+    // A value that is not less than the modulus is reduced, so it does not display as the digits it was parsed from.
+    let field = <Testnet3 as Environment>::Field::from_str(digits)
+        .ok()
+        .filter(|field| field.to_string() == digits)
+        .ok_or(FieldParseError::OutOfModulus)?;
+    Ok(if negative { -field } else { field })
+}
+
+/// Returns whether the digits of an integer literal, e.g. `1_000` or `-5`, are a value of `integer_type`.
+fn is_integer_in_range(integer_type: IntegerType, string: &str) -> bool {
+    let digits = string.replace('_', "");
+    match integer_type {
+        IntegerType::U8 => digits.parse::<u8>().is_ok(),
+        IntegerType::U16 => digits.parse::<u16>().is_ok(),
+        IntegerType::U32 => digits.parse::<u32>().is_ok(),
+        IntegerType::U64 => digits.parse::<u64>().is_ok(),
+        IntegerType::U128 => digits.parse::<u128>().is_ok(),
+        IntegerType::I8 => digits.parse::<i8>().is_ok(),
+        IntegerType::I16 => digits.parse::<i16>().is_ok(),
+        IntegerType::I32 => digits.parse::<i32>().is_ok(),
+        IntegerType::I64 => digits.parse::<i64>().is_ok(),
+        IntegerType::I128 => digits.parse::<i128>().is_ok(),
+    }
+}
+
+/// Returns the innermost element type if the type is an array, otherwise returns the type itself.
+/// Arithmetic over arrays is applied element-wise, so the element type determines which operations are valid.
+fn element_wise_type(type_: &Option<Type>) -> Option<Type> {
+    match type_ {
+        Some(Type::Array(array_type)) => element_wise_type(&Some(array_type.element_type().clone())),
+        _ => type_.clone(),
+    }
+}
+
 impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     type AdditionalInput = Option<Type>;
     type Output = Option<Type>;
@@ -49,6 +113,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             Expression::Binary(binary) => self.visit_binary(binary, additional),
             Expression::Call(call) => self.visit_call(call, additional),
             Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Repeat(repeat) => self.visit_repeat(repeat, additional),
             Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
             Expression::Err(err) => self.visit_err(err, additional),
             Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
@@ -306,9 +371,41 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         return_type
     }
 
+    fn visit_repeat(&mut self, input: &'a RepeatExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Get the type of the element expression.
+        let element_type = self.visit_expression(&input.element, &None);
+
+        // Look up the count, which may be the name of a `u32` constant.
+        let count = self.resolve_array_length(&input.count);
+        if count.is_none() {
+            self.emit_err(TypeCheckerError::array_length_not_constant(&input.count, input.span()));
+        }
+
+        // Construct the array type, and check that its length and element type are valid.
+        let return_type = match (element_type, count) {
+            (Some(element_type), Some(count)) => {
+                let type_ = Type::Array(ArrayType::new(element_type, NonNegativeNumber::from(count)));
+                self.assert_type_is_valid(&type_, input.span()).then_some(type_)
+            }
+            _ => None,
+        };
+
+        // If the expected type is known, then check that the array type is the same as the expected type.
+        if let Some(expected) = additional {
+            self.assert_type(&return_type, expected, input.span());
+        }
+
+        // Return the array type.
+        return_type
+    }
+
     fn visit_binary(&mut self, input: &'a BinaryExpression, destination: &Self::AdditionalInput) -> Self::Output {
         match input.op {
-            BinaryOperation::And | BinaryOperation::Or | BinaryOperation::Nand | BinaryOperation::Nor => {
+            BinaryOperation::And
+            | BinaryOperation::Or
+            | BinaryOperation::LogicalXor
+            | BinaryOperation::Nand
+            | BinaryOperation::Nor => {
                 // Only boolean types.
                 self.assert_bool_type(destination, input.span());
                 let t1 = self.visit_expression(&input.left, destination);
@@ -331,8 +428,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return_incorrect_type(t1, t2, destination)
             }
             BinaryOperation::Add => {
-                // Only field, group, scalar, or integer types.
-                self.assert_field_group_scalar_int_type(destination, input.span());
+                // Only field, group, scalar, or integer types, or arrays of them.
+                self.assert_field_group_scalar_int_type(&element_wise_type(destination), input.span());
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
@@ -342,8 +439,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return_incorrect_type(t1, t2, destination)
             }
             BinaryOperation::Sub => {
-                // Only field, group, or integer types.
-                self.assert_field_group_int_type(destination, input.span());
+                // Only field, group, or integer types, or arrays of them.
+                self.assert_field_group_int_type(&element_wise_type(destination), input.span());
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
@@ -353,14 +450,28 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 return_incorrect_type(t1, t2, destination)
             }
             BinaryOperation::Mul => {
-                // Operation returns field, group or integer types.
-                self.assert_field_group_int_type(destination, input.span());
+                // Operation returns field, group or integer types, or arrays of them.
+                self.assert_field_group_int_type(&element_wise_type(destination), input.span());
 
                 let t1 = self.visit_expression(&input.left, &None);
                 let t2 = self.visit_expression(&input.right, &None);
 
                 // Allow group * scalar multiplication.
                 match (t1, input.left.span(), t2, input.right.span()) {
+                    (Some(Type::Array(array_type)), _, other, other_span) => {
+                        let type_ = Type::Array(array_type);
+
+                        // Arrays are multiplied element-wise, so the other operand must be the same array type.
+                        self.assert_type(&other, &type_, other_span);
+
+                        // The elements must be fields or integers.
+                        self.assert_field_int_type(&element_wise_type(&Some(type_.clone())), input.left.span());
+
+                        // Operation returns the same array type.
+                        self.assert_type(destination, &type_, input.span());
+
+                        Some(type_)
+                    }
                     (Some(Type::Group), _, other, other_span) | (other, other_span, Some(Type::Group), _) => {
                         // Other type must be scalar.
                         self.assert_scalar_type(&other, other_span);
@@ -415,6 +526,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(&input.right);
+
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
@@ -427,6 +541,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(&input.right);
+
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
@@ -439,6 +556,9 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
+                // Check that the divisor is not a literal zero.
+                self.assert_nonzero_divisor(&input.right);
+
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
@@ -535,6 +655,24 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 let t1 = self.visit_expression(&input.left, destination);
                 let t2 = self.visit_expression(&input.right, destination);
 
+                // Check that the divisor is not a literal zero.
+                if input.op == BinaryOperation::DivWrapped {
+                    self.assert_nonzero_divisor(&input.right);
+                }
+
+                // Check that both operands have the same type.
+                self.check_eq_types(&t1, &t2, input.span());
+
+                return_incorrect_type(t1, t2, destination)
+            }
+            BinaryOperation::AddSaturating | BinaryOperation::SubSaturating => {
+                let t1 = self.visit_expression(&input.left, destination);
+                let t2 = self.visit_expression(&input.right, destination);
+
+                // Only unsigned integer types.
+                // The operand is checked, rather than the destination, since the destination type may not be known.
+                self.assert_unsigned_int_type(&t1, input.left.span());
+
                 // Check that both operands have the same type.
                 self.check_eq_types(&t1, &t2, input.span());
 
@@ -561,7 +699,93 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_call(&mut self, input: &'a CallExpression, expected: &Self::AdditionalInput) -> Self::Output {
+        // The name of the array built-in being called, if any.
+        // Note that a user-defined function of the same name takes precedence over the built-in.
+        let builtin = match &*input.function {
+            Expression::Identifier(ident)
+                if input.external.is_none() && self.symbol_table.borrow().lookup_fn_symbol(ident.name).is_none() =>
+            {
+                Some(ident.name)
+            }
+            _ => None,
+        };
+
         match &*input.function {
+            // `len(a)` returns the length of the array `a`.
+            Expression::Identifier(_) if builtin == Some(sym::len) => {
+                // Check number of function arguments.
+                if input.arguments.len() != 1 {
+                    self.emit_err(TypeCheckerError::incorrect_num_args_to_call(1, input.arguments.len(), input.span()));
+                }
+
+                // Check that the argument is an array.
+                input.arguments.iter().for_each(|argument| {
+                    let type_ = self.visit_expression(argument, &None);
+                    self.assert_array_type(&type_, argument.span());
+                });
+
+                Some(self.assert_and_return_type(Type::Integer(IntegerType::U32), expected, input.span()))
+            }
+            // `concat(a, b)` returns an array containing the elements of `a` followed by the elements of `b`.
+            Expression::Identifier(_) if builtin == Some(sym::concat) => {
+                // Check number of function arguments.
+                if input.arguments.len() != 2 {
+                    self.emit_err(TypeCheckerError::incorrect_num_args_to_call(2, input.arguments.len(), input.span()));
+                }
+
+                // Check that the arguments are arrays.
+                let types = input
+                    .arguments
+                    .iter()
+                    .map(|argument| {
+                        let type_ = self.visit_expression(argument, &None);
+                        self.assert_array_type(&type_, argument.span());
+                        type_
+                    })
+                    .collect::<Vec<_>>();
+
+                match (types.first(), types.get(1)) {
+                    (Some(Some(Type::Array(first))), Some(Some(Type::Array(second)))) => {
+                        // Check that the arrays have the same element type.
+                        self.assert_type(
+                            &Some(second.element_type().clone()),
+                            first.element_type(),
+                            input.arguments[1].span(),
+                        );
+
+                        // Check that the resulting array is not too large.
+                        let length = first.length() + second.length();
+                        if length > Testnet3::MAX_ARRAY_ELEMENTS {
+                            self.emit_err(TypeCheckerError::array_too_large(
+                                length,
+                                Testnet3::MAX_ARRAY_ELEMENTS,
+                                input.span(),
+                            ));
+                        }
+
+                        let type_ =
+                            Type::Array(ArrayType::new(first.element_type().clone(), NonNegativeNumber::from(length)));
+                        Some(self.assert_and_return_type(type_, expected, input.span()))
+                    }
+                    _ => None,
+                }
+            }
+            // `any(a)` returns whether any element of the boolean array `a` is true.
+            // `all(a)` returns whether every element of the boolean array `a` is true.
+            Expression::Identifier(ident) if matches!(builtin, Some(sym::any | sym::all)) => {
+                // Check number of function arguments.
+                if input.arguments.len() != 1 {
+                    self.emit_err(TypeCheckerError::incorrect_num_args_to_call(1, input.arguments.len(), input.span()));
+                }
+
+                // Check that the argument is an array of booleans.
+                input.arguments.iter().for_each(|argument| {
+                    let type_ = self.visit_expression(argument, &None);
+                    self.assert_boolean_array_type(&type_, argument.span());
+                });
+
+                Some(self.assert_and_return_type(Type::Boolean, expected, input.span()))
+            }
             // Note that the parser guarantees that `input.function` is always an identifier.
             Expression::Identifier(ident) => {
                 // Note: The function symbol lookup is performed outside of the `if let Some(func) ...` block to avoid a RefCell lifetime bug in Rust.
@@ -596,9 +820,11 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                     let ret = self.assert_and_return_type(func.output_type, expected, input.span());
 
                     // Check number of function arguments.
-                    if func.input.len() != input.arguments.len() {
+                    // Note that trailing parameters with a default value may be omitted.
+                    let num_required = func.input.iter().filter(|input| input.default_value().is_none()).count();
+                    if input.arguments.len() < num_required || input.arguments.len() > func.input.len() {
                         self.emit_err(TypeCheckerError::incorrect_num_args_to_call(
-                            func.input.len(),
+                            if input.arguments.len() < num_required { num_required } else { func.input.len() },
                             input.arguments.len(),
                             input.span(),
                         ));
@@ -634,8 +860,21 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let expression_type = self.visit_expression(&input.expression, &None);
         self.assert_castable_type(&expression_type, input.expression.span());
 
+        // Check that an integer literal is in the range of the integer type it is cast to, since the cast would always fail.
+        if let (Expression::Literal(Literal::Integer(integer_type, string, ..)), Type::Integer(target)) =
+            (&*input.expression, &input.type_)
+        {
+            if !is_integer_in_range(*target, string) {
+                self.emit_err(TypeCheckerError::literal_cast_out_of_range(
+                    format!("{string}{integer_type}"),
+                    target,
+                    input.span(),
+                ));
+            }
+        }
+
         // Check that the expected type matches the target type.
-        Some(self.assert_and_return_type(input.type_.clone(), expected, input.span()))
+        Some(self.assert_and_return_type(self.resolve_type(&input.type_), expected, input.span()))
     }
 
     fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
@@ -644,13 +883,33 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
             // Check struct type name.
             let ret = self.check_expected_struct(struct_.identifier, additional, input.name.span());
 
-            // Check number of struct members.
-            if struct_.members.len() != input.members.len() {
-                self.emit_err(TypeCheckerError::incorrect_num_struct_members(
-                    struct_.members.len(),
-                    input.members.len(),
-                    input.span(),
-                ));
+            match &input.base {
+                // The base struct must have the same type, and fills in any members that are not initialized.
+                Some(base) => {
+                    self.visit_expression(base, &Some(ret.clone()));
+
+                    // Check that each initialized member is a member of the struct.
+                    input
+                        .members
+                        .iter()
+                        .filter(|member| !struct_.members.iter().any(|m| m.identifier.name == member.identifier.name))
+                        .for_each(|member| {
+                            self.emit_err(TypeCheckerError::invalid_struct_variable(
+                                member.identifier,
+                                struct_.identifier,
+                                member.span(),
+                            ))
+                        });
+                }
+                // Check number of struct members.
+                None if struct_.members.len() != input.members.len() => {
+                    self.emit_err(TypeCheckerError::incorrect_num_struct_members(
+                        struct_.members.len(),
+                        input.members.len(),
+                        input.span(),
+                    ));
+                }
+                None => {}
             }
 
             // Check struct member types.
@@ -663,7 +922,7 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                         // Otherwise, visit the associated expression.
                         Some(expr) => self.visit_expression(expr, &Some(type_.clone())),
                     };
-                } else {
+                } else if input.base.is_none() {
                     self.emit_err(TypeCheckerError::missing_struct_member(
                         struct_.identifier,
                         identifier,
@@ -686,9 +945,22 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
 
     fn visit_identifier(&mut self, input: &'a Identifier, expected: &Self::AdditionalInput) -> Self::Output {
         if let Some(var) = self.symbol_table.borrow().lookup_variable(input.name) {
+            self.use_variable(var.span);
             Some(self.assert_and_return_type(var.type_.clone(), expected, input.span()))
         } else {
-            self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, input.span()));
+            // Report functions, structs, and records by name, since they are defined but are not values.
+            let symbol_table = self.symbol_table.borrow();
+            let kind = if symbol_table.lookup_fn_symbol(input.name).is_some() {
+                Some("function")
+            } else {
+                symbol_table
+                    .lookup_struct(input.name)
+                    .map(|struct_| if struct_.is_record { "record" } else { "struct" })
+            };
+            match kind {
+                Some(kind) => self.emit_err(TypeCheckerError::definition_used_as_value(kind, input.name, input.span())),
+                None => self.emit_err(TypeCheckerError::unknown_sym("variable", input.name, input.span())),
+            }
             None
         }
     }
@@ -704,7 +976,20 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         Some(match input {
             Literal::Address(_, _, _) => self.assert_and_return_type(Type::Address, expected, input.span()),
             Literal::Boolean(_, _, _) => self.assert_and_return_type(Type::Boolean, expected, input.span()),
-            Literal::Field(_, _, _) => self.assert_and_return_type(Type::Field, expected, input.span()),
+            Literal::Field(string, _, _) => {
+                // Check that the field literal is less than the field modulus, since snarkVM would silently reduce it.
+                match parse_field_literal(string) {
+                    Ok(_) => {}
+                    Err(FieldParseError::Empty) => self.emit_err(TypeCheckerError::empty_field_literal(input.span())),
+                    Err(FieldParseError::Malformed) => {
+                        self.emit_err(TypeCheckerError::malformed_field_literal(string, input.span()))
+                    }
+                    Err(FieldParseError::OutOfModulus) => {
+                        self.emit_err(TypeCheckerError::field_literal_out_of_modulus(string, input.span()))
+                    }
+                }
+                self.assert_and_return_type(Type::Field, expected, input.span())
+            }
             Literal::Integer(integer_type, string, _, _) => match integer_type {
                 IntegerType::U8 => {
                     parse_integer_literal::<u8>(self.handler, string, input.span(), "u8");
@@ -762,6 +1047,12 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         let t1 = self.visit_expression(&input.if_true, expected);
         let t2 = self.visit_expression(&input.if_false, expected);
 
+        // Check that both branches have the same type.
+        // Note that this is only needed if the expected type is unknown, since otherwise both branches have already been checked against it.
+        if expected.is_none() {
+            self.check_eq_types(&t1, &t2, input.span());
+        }
+
         return_incorrect_type(t1, t2, expected)
     }
 
@@ -829,8 +1120,8 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
                 type_
             }
             UnaryOperation::Not => {
-                // Only boolean or integer types.
-                self.assert_bool_int_type(destination, input.span());
+                // Only boolean or integer types, or arrays of them.
+                self.assert_bool_int_type(&element_wise_type(destination), input.span());
                 self.visit_expression(&input.receiver, destination)
             }
             UnaryOperation::Square => {
@@ -859,3 +1150,48 @@ impl<'a> ExpressionVisitor<'a> for TypeChecker<'a> {
         Some(Type::Unit)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The modulus of the scalar field of BLS12-377, which is the field of Leo's `field` type.
+    const MODULUS: &str = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+
+    #[test]
+    fn test_parse_field_literal_round_trip() {
+        // Walk through a deterministic sequence of field elements, including zero and the largest element.
+        let mut value = <Testnet3 as Environment>::Field::from_str("0").unwrap();
+        let step = parse_field_literal("-7").unwrap();
+        for _ in 0..1000 {
+            let string = value.to_string();
+            assert_eq!(parse_field_literal(&string), Ok(value), "{string}");
+            value = value * step + step;
+        }
+    }
+
+    #[test]
+    fn test_parse_field_literal_edge_cases() {
+        let parse = |string: &str| parse_field_literal(string).map(|field| field.to_string());
+
+        assert_eq!(parse("0"), Ok("0".to_string()));
+        assert_eq!(parse("000"), Ok("0".to_string()));
+        assert_eq!(parse("-0"), Ok("0".to_string()));
+        assert_eq!(parse("007"), Ok("7".to_string()));
+        assert_eq!(parse("1_000"), Ok("1000".to_string()));
+        assert_eq!(
+            parse("-1"),
+            Ok("8444461749428370424248824938781546531375899335154063827935233455917409239040".to_string())
+        );
+
+        assert_eq!(parse(""), Err(FieldParseError::Empty));
+        assert_eq!(parse("-"), Err(FieldParseError::Empty));
+        assert_eq!(parse("_"), Err(FieldParseError::Empty));
+        assert_eq!(parse("1a"), Err(FieldParseError::Malformed));
+        assert_eq!(parse("+1"), Err(FieldParseError::Malformed));
+        assert_eq!(parse("--1"), Err(FieldParseError::Malformed));
+        assert_eq!(parse(MODULUS), Err(FieldParseError::OutOfModulus));
+        assert_eq!(parse(&format!("-{MODULUS}")), Err(FieldParseError::OutOfModulus));
+        assert_eq!(parse(&"9".repeat(1000)), Err(FieldParseError::OutOfModulus));
+    }
+}
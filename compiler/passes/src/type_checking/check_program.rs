@@ -56,6 +56,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Typecheck each const definition, and append to symbol table.
         input.consts.iter().for_each(|(_, c)| self.visit_const(c));
 
+        // Now that the program constants are known, resolve the array lengths in the signatures of the program scope.
+        self.resolve_signatures(input);
+
         // Typecheck each struct definition.
         input.structs.iter().for_each(|(_, function)| self.visit_struct(function));
 
@@ -101,6 +104,11 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                 input.program_id.name.span + input.program_id.network.span,
             ));
         }
+
+        // Check that the program has at least one transition, since snarkVM rejects programs without any.
+        if transition_count == 0 && !self.is_imported {
+            self.emit_err(TypeCheckerError::no_transitions(input.program_id.name.span + input.program_id.network.span));
+        }
     }
 
     fn visit_struct(&mut self, input: &'a Struct) {
@@ -232,10 +240,34 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
         // Create a new child scope for the function's parameters and body.
         let scope_index = self.create_child_scope();
 
+        // Whether a previous parameter has a default value.
+        let mut has_default = false;
+
         // Type check the function's parameters.
         function.input.iter().for_each(|input_var| {
             // Check that the type of input parameter is defined.
             self.assert_type_is_valid(&input_var.type_(), input_var.span());
+
+            // Check that a default value is a literal of the parameter's type, and that only trailing parameters have one.
+            match input_var.default_value() {
+                Some(default) => {
+                    // Defaults are only filled in at Leo call sites, so transitions cannot have them.
+                    if self.variant == Some(Variant::Transition) {
+                        self.emit_err(TypeCheckerError::transition_input_cannot_have_default(default.span()));
+                    }
+                    if !matches!(default, Expression::Literal(_)) {
+                        self.emit_err(TypeCheckerError::default_input_must_be_literal(
+                            input_var.identifier(),
+                            default.span(),
+                        ));
+                    }
+                    self.visit_expression(default, &Some(self.resolve_type(&input_var.type_())));
+                    has_default = true;
+                }
+                None if has_default => self
+                    .emit_err(TypeCheckerError::required_input_after_default(input_var.identifier(), input_var.span())),
+                None => {}
+            }
             // Check that the type of the input parameter is not a tuple.
             if matches!(input_var.type_(), Type::Tuple(_)) {
                 self.emit_err(TypeCheckerError::function_cannot_take_tuple_as_input(input_var.span()))
@@ -255,14 +287,19 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             }
 
             // Check for conflicting variable names.
-            if let Err(err) =
-                self.symbol_table.borrow_mut().insert_variable(input_var.identifier().name, VariableSymbol {
-                    type_: input_var.type_(),
-                    span: input_var.identifier().span(),
-                    declaration: VariableType::Input(input_var.mode()),
-                })
-            {
-                self.handler.emit_err(err);
+            let type_ = self.resolve_type(&input_var.type_());
+            match self.symbol_table.borrow_mut().insert_variable(input_var.identifier().name, VariableSymbol {
+                type_,
+                span: input_var.identifier().span(),
+                declaration: VariableType::Input(input_var.mode()),
+                value: None,
+            }) {
+                // The inputs of a transition are part of the program's interface, so they are not linted.
+                Ok(()) if self.variant != Some(Variant::Transition) => {
+                    self.declare_variable(input_var.identifier().name, input_var.identifier().span())
+                }
+                Ok(()) => {}
+                Err(err) => self.handler.emit_err(err),
             }
         });
 
@@ -345,6 +382,10 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             let scope_index = self.create_child_scope();
 
             finalize.input.iter().for_each(|input_var| {
+                // Check that the input parameter does not have a default value.
+                if let Some(default) = input_var.default_value() {
+                    self.emit_err(TypeCheckerError::finalize_input_cannot_have_default(default.span()));
+                }
                 // Check that the type of input parameter is defined.
                 if self.assert_type_is_valid(&input_var.type_(), input_var.span()) {
                     // Check that the input parameter is not a tuple.
@@ -363,11 +404,13 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
                         self.emit_err(TypeCheckerError::finalize_input_mode_must_be_public(input_var.span()));
                     }
                     // Check for conflicting variable names.
+                    let type_ = self.resolve_type(&input_var.type_());
                     if let Err(err) =
                         self.symbol_table.borrow_mut().insert_variable(input_var.identifier().name, VariableSymbol {
-                            type_: input_var.type_(),
+                            type_,
                             span: input_var.identifier().span(),
                             declaration: VariableType::Input(input_var.mode()),
+                            value: None,
                         })
                     {
                         self.handler.emit_err(err);
@@ -430,6 +473,9 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
             self.is_finalize = false;
         }
 
+        // Warn about any variables of the function or its finalize block that were never read.
+        self.warn_unused_variables();
+
         // Exit the function's scope.
         self.exit_scope(function_index);
 
@@ -19,7 +19,30 @@ use itertools::Itertools;
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
+
+/// Keywords that the parser accepts as identifier expressions, and so must be rejected as variable names.
+const RESERVED_VARIABLE_NAMES: &[Symbol] = &[
+    sym::address,
+    sym::block,
+    sym::bool,
+    sym::field,
+    sym::group,
+    sym::i8,
+    sym::i16,
+    sym::i32,
+    sym::i64,
+    sym::i128,
+    sym::scalar,
+    sym::SelfLower,
+    sym::signature,
+    sym::string,
+    sym::u8,
+    sym::u16,
+    sym::u32,
+    sym::u64,
+    sym::u128,
+];
 
 impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     fn visit_statement(&mut self, input: &'a Statement) {
@@ -69,10 +92,14 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         };
 
         let var_type = if let Some(var) = self.symbol_table.borrow_mut().lookup_variable(var_name.name) {
+            // `let` bindings and non-constant inputs are mutable.
+            // Constants, loop variables, and constant inputs are not, so the assignment itself is reported.
             match &var.declaration {
-                VariableType::Const => self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var.span)),
+                VariableType::Const => {
+                    self.emit_err(TypeCheckerError::cannot_assign_to_const_var(var_name, var_name.span))
+                }
                 VariableType::Input(Mode::Constant) => {
-                    self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var.span))
+                    self.emit_err(TypeCheckerError::cannot_assign_to_const_input(var_name, var_name.span))
                 }
                 _ => {}
             }
@@ -189,13 +216,18 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        let type_ = self.resolve_type(&input.type_);
+        self.visit_expression(&input.value, &Some(type_.clone()));
 
         // Add constants to symbol table so that any references to them in later statements will pass TC
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.place.name, VariableSymbol {
-            type_: input.type_.clone(),
+            type_,
             span: input.place.span,
             declaration: VariableType::Const,
+            value: match &input.value {
+                Expression::Literal(literal) => Some(literal.clone()),
+                _ => None,
+            },
         }) {
             self.handler.emit_err(err);
         }
@@ -226,27 +258,32 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
 
         // Check the expression on the right-hand side.
-        self.visit_expression(&input.value, &Some(input.type_.clone()));
+        let type_ = self.resolve_type(&input.type_);
+        self.visit_expression(&input.value, &Some(type_.clone()));
 
         // TODO: Dedup with unrolling pass.
         // Helper to insert the variables into the symbol table.
         let insert_variable = |symbol: Symbol, type_: Type, span: Span| {
-            if let Err(err) = self.symbol_table.borrow_mut().insert_variable(symbol, VariableSymbol {
+            // Type names, `self`, and `block` parse as identifiers in expressions, but cannot name a variable.
+            if RESERVED_VARIABLE_NAMES.contains(&symbol) {
+                self.emit_err(TypeCheckerError::reserved_variable_name(symbol, span));
+            }
+            match self.symbol_table.borrow_mut().insert_variable(symbol, VariableSymbol {
                 type_,
                 span,
                 declaration: VariableType::Mut,
+                value: None,
             }) {
-                self.handler.emit_err(err);
+                Ok(()) => self.declare_variable(symbol, span),
+                Err(err) => self.handler.emit_err(err),
             }
         };
 
         // Insert the variables into the symbol table.
         match &input.place {
-            Expression::Identifier(identifier) => {
-                insert_variable(identifier.name, input.type_.clone(), identifier.span)
-            }
+            Expression::Identifier(identifier) => insert_variable(identifier.name, type_, identifier.span),
             Expression::Tuple(tuple_expression) => {
-                let tuple_type = match &input.type_ {
+                let tuple_type = match &type_ {
                     Type::Tuple(tuple_type) => tuple_type,
                     _ => unreachable!(
                         "Type checking guarantees that if the lhs is a tuple, its associated type is also a tuple."
@@ -303,6 +340,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             type_: input.type_.clone(),
             span: input.span(),
             declaration: VariableType::Const,
+            value: None,
         }) {
             self.handler.emit_err(err);
         }
@@ -337,14 +375,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                     input.start_value.replace(Some(value));
                 }
             }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
-                    }
-                }
-            }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.start.span())),
+            _ => self.assert_constant_loop_bound(&input.start),
         }
 
         self.visit_expression(&input.stop, iter_type);
@@ -357,14 +388,7 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
                     input.stop_value.replace(Some(value));
                 }
             }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
-                    }
-                }
-            }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.stop.span())),
+            _ => self.assert_constant_loop_bound(&input.stop),
         }
     }
 
@@ -393,8 +417,16 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
         // Set the `is_return` flag. This is necessary to allow unit expressions in the return statement.
         self.is_return = true;
-        // Type check the associated expression.
-        self.visit_expression(&input.expression, return_type);
+        // A function without a declared return type returns `()`.
+        // Report a returned value once, instead of reporting each of its subexpressions as a type mismatch.
+        if matches!(return_type, Some(Type::Unit)) {
+            if !matches!(self.visit_expression(&input.expression, &None), None | Some(Type::Unit | Type::Err)) {
+                self.emit_err(TypeCheckerError::return_value_without_return_type(parent, input.expression.span()));
+            }
+        } else {
+            // Type check the associated expression.
+            self.visit_expression(&input.expression, return_type);
+        }
         // Unset the `is_return` flag.
         self.is_return = false;
 
@@ -14,14 +14,32 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
-
-use leo_ast::{CoreConstant, CoreFunction, Identifier, IntegerType, MappingType, Node, Type, Variant};
-use leo_errors::{emitter::Handler, TypeCheckerError};
+use crate::{CallGraph, StructGraph, SymbolTable, TypeTable, VariableSymbol, VariableType};
+
+use leo_ast::{
+    ArrayLength,
+    BinaryOperation,
+    CoreConstant,
+    CoreFunction,
+    Expression,
+    FunctionInput,
+    Identifier,
+    Input,
+    IntegerType,
+    Literal,
+    MappingType,
+    Member,
+    Node,
+    ProgramScope,
+    Type,
+    Variant,
+};
+use leo_errors::{emitter::Handler, TypeCheckerError, TypeCheckerWarning};
 use leo_span::{Span, Symbol};
 
 use snarkvm_console::network::{Network, Testnet3};
 
+use indexmap::IndexMap;
 use itertools::Itertools;
 use std::cell::RefCell;
 
@@ -51,6 +69,9 @@ pub struct TypeChecker<'a> {
     pub(crate) is_imported: bool,
     /// Whether or not we are currently traversing a return statement.
     pub(crate) is_return: bool,
+    /// The variables of the function that we are currently traversing that have not yet been read,
+    /// keyed by the span of their declaration.
+    pub(crate) unused_variables: RefCell<IndexMap<Span, Symbol>>,
 }
 
 const ADDRESS_TYPE: Type = Type::Address;
@@ -118,6 +139,7 @@ impl<'a> TypeChecker<'a> {
             is_finalize: false,
             is_imported: false,
             is_return: false,
+            unused_variables: Default::default(),
         }
     }
 
@@ -150,6 +172,31 @@ impl<'a> TypeChecker<'a> {
         self.handler.emit_err(err);
     }
 
+    /// Emits a type checker warning.
+    pub(crate) fn emit_warning(&self, warning: TypeCheckerWarning) {
+        self.handler.emit_warning(warning.into());
+    }
+
+    /// Records a variable that should be read before the end of the current function.
+    /// Variables of imported programs are not linted.
+    pub(crate) fn declare_variable(&self, name: Symbol, span: Span) {
+        if !self.is_imported {
+            self.unused_variables.borrow_mut().insert(span, name);
+        }
+    }
+
+    /// Marks the variable declared at `span` as read.
+    pub(crate) fn use_variable(&self, span: Span) {
+        self.unused_variables.borrow_mut().shift_remove(&span);
+    }
+
+    /// Emits a warning for each variable of the current function that was never read.
+    pub(crate) fn warn_unused_variables(&self) {
+        for (span, name) in self.unused_variables.take() {
+            self.emit_warning(TypeCheckerWarning::unused_variable(name, span));
+        }
+    }
+
     /// Emits an error to the handler if the given type is invalid.
     fn check_type(&self, is_valid: impl Fn(&Type) -> bool, error_string: String, type_: &Option<Type>, span: Span) {
         if let Some(type_) = type_ {
@@ -1087,10 +1134,14 @@ impl<'a> TypeChecker<'a> {
             // Check that the array element types are valid.
             Type::Array(array_type) => {
                 // Check that the array length is valid.
-                match array_type.length() {
-                    0 => self.emit_err(TypeCheckerError::array_empty(span)),
-                    1..=Testnet3::MAX_ARRAY_ELEMENTS => {}
-                    length => {
+                match self.resolve_array_length(array_type.array_length()) {
+                    None => {
+                        is_valid = false;
+                        self.emit_err(TypeCheckerError::array_length_not_constant(array_type.array_length(), span))
+                    }
+                    Some(0) => self.emit_err(TypeCheckerError::array_empty(span)),
+                    Some(1..=Testnet3::MAX_ARRAY_ELEMENTS) => {}
+                    Some(length) => {
                         self.emit_err(TypeCheckerError::array_too_large(length, Testnet3::MAX_ARRAY_ELEMENTS, span))
                     }
                 }
@@ -1117,6 +1168,72 @@ impl<'a> TypeChecker<'a> {
         is_valid
     }
 
+    /// Returns the value of an array length, looking up the `u32` constant in scope if it is given by name.
+    /// Returns `None` if the name does not refer to a `u32` constant.
+    pub(crate) fn resolve_array_length(&self, length: &ArrayLength) -> Option<usize> {
+        match length {
+            ArrayLength::Literal(length) => Some(length.value()),
+            ArrayLength::Constant(name) => match self.symbol_table.borrow().lookup_variable(name.name) {
+                Some(VariableSymbol {
+                    declaration: VariableType::Const,
+                    value: Some(Literal::Integer(IntegerType::U32, value, ..)),
+                    ..
+                }) => value.replace('_', "").parse().ok(),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the type with each array length given by a constant replaced with the value of the constant.
+    /// An array whose length cannot be resolved becomes `Type::Err`, which `assert_type_is_valid` reports.
+    pub(crate) fn resolve_type(&self, type_: &Type) -> Type {
+        type_.resolve_array_lengths(&|name| self.resolve_array_length(&ArrayLength::Constant(*name)))
+    }
+
+    /// Resolves the array lengths in the symbol table entries of the structs, mappings, and functions of a program scope.
+    /// Later lookups of these entries, e.g. at struct initializers and call sites, then see the resolved types.
+    pub(crate) fn resolve_signatures(&self, input: &ProgramScope) {
+        let resolve_input = |input: Input| match input {
+            Input::Internal(input) => {
+                Input::Internal(FunctionInput { type_: self.resolve_type(&input.type_), ..input })
+            }
+            Input::External(_) => input,
+        };
+
+        for (name, _) in input.structs.iter() {
+            let struct_ = self.symbol_table.borrow().lookup_struct(*name).cloned();
+            if let Some(mut struct_) = struct_ {
+                struct_.members = struct_
+                    .members
+                    .into_iter()
+                    .map(|member| Member { type_: self.resolve_type(&member.type_), ..member })
+                    .collect();
+                self.symbol_table.borrow_mut().structs.insert(*name, struct_);
+            }
+        }
+
+        for (name, _) in input.mappings.iter() {
+            let type_ =
+                self.symbol_table.borrow().lookup_variable(*name).map(|mapping| self.resolve_type(&mapping.type_));
+            if let (Some(type_), Some(mapping)) = (type_, self.symbol_table.borrow_mut().lookup_variable_mut(*name)) {
+                mapping.type_ = type_;
+            }
+        }
+
+        for (name, _) in input.functions.iter() {
+            let function = self.symbol_table.borrow().lookup_fn_symbol(*name).cloned();
+            if let Some(mut function) = function {
+                function.input = function.input.into_iter().map(resolve_input).collect();
+                function.output_type = self.resolve_type(&function.output_type);
+                if let Some(finalize) = function.finalize.as_mut() {
+                    finalize.input = std::mem::take(&mut finalize.input).into_iter().map(resolve_input).collect();
+                    finalize.output_type = self.resolve_type(&finalize.output_type);
+                }
+                self.symbol_table.borrow_mut().functions.insert(*name, function);
+            }
+        }
+    }
+
     /// Emits an error if the type is not a mapping.
     pub(crate) fn assert_mapping_type(&self, type_: &Option<Type>, span: Span) -> Option<MappingType> {
         self.check_type(|type_| matches!(type_, Type::Mapping(_)), "mapping".to_string(), type_, span);
@@ -1130,6 +1247,58 @@ impl<'a> TypeChecker<'a> {
     pub(crate) fn assert_array_type(&self, type_: &Option<Type>, span: Span) {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
+
+    /// Emits an error if the type is not an array of booleans.
+    pub(crate) fn assert_boolean_array_type(&self, type_: &Option<Type>, span: Span) {
+        self.check_type(
+            |type_| matches!(type_, Type::Array(array_type) if array_type.element_type() == &Type::Boolean),
+            "array of booleans".to_string(),
+            type_,
+            span,
+        );
+    }
+
+    /// Emits an error if a loop bound is not a literal, a const, or a `+`, `-`, or `*` expression of them.
+    /// The loop unroller evaluates these bounds after propagating constants.
+    pub(crate) fn assert_constant_loop_bound(&self, bound: &Expression) {
+        match bound {
+            Expression::Literal(_) => {}
+            Expression::Identifier(id) => {
+                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
+                    if VariableType::Const != var.declaration {
+                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
+                    }
+                }
+            }
+            Expression::Binary(binary)
+                if matches!(binary.op, BinaryOperation::Add | BinaryOperation::Sub | BinaryOperation::Mul) =>
+            {
+                self.assert_constant_loop_bound(&binary.left);
+                self.assert_constant_loop_bound(&binary.right);
+            }
+            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(bound.span())),
+        }
+    }
+
+    /// Emits an error if the divisor is a literal zero.
+    /// Divisors that are only known at runtime are checked by the `div` and `rem` instructions in snarkVM.
+    pub(crate) fn assert_nonzero_divisor(&self, divisor: &Expression) {
+        let is_zero = |value: &str| {
+            let digits = value.replace('_', "");
+            let digits = digits.trim_start_matches('-');
+            !digits.is_empty() && digits.chars().all(|c| c == '0')
+        };
+
+        match divisor {
+            Expression::Literal(Literal::Integer(_, value, span, _))
+            | Expression::Literal(Literal::Field(value, span, _))
+                if is_zero(value) =>
+            {
+                self.emit_err(TypeCheckerError::division_by_zero(*span))
+            }
+            _ => {}
+        }
+    }
 }
 
 fn types_to_string(types: &[Type]) -> String {
@@ -113,6 +113,7 @@ symbols! {
 
     // binary operators
     add,
+    add_saturating,
     add_wrapped,
     and,
     div,
@@ -138,6 +139,7 @@ symbols! {
     shr,
     shr_wrapped,
     sub,
+    sub_saturating,
     sub_wrapped,
     xor,
 
@@ -203,6 +205,12 @@ symbols! {
     to_y_coordinate,
     verify,
 
+    // array functions
+    all,
+    any,
+    concat,
+    len,
+
     // types
     address,
     bool,
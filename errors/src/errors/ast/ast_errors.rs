@@ -145,4 +145,12 @@ create_messages!(
         msg: format!("failed to convert symbol_table to a json value {error}"),
         help: None,
     }
+
+    /// For when two programs being merged both define the same item.
+    @formatted
+    conflicting_definition_in_merge {
+        args: (kind: impl Display, name: impl Display, program: impl Display),
+        msg: format!("cannot merge programs: {kind} `{name}` is already defined in `{program}`"),
+        help: Some("Rename one of the definitions.".to_string()),
+    }
 );
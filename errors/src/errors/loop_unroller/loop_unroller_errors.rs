@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::create_messages;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 create_messages!(
     /// LoopUnrollerError enum that represents all the errors for the loop unrolling errors in the `leo-loop_unroller` crate.
@@ -36,4 +36,25 @@ create_messages!(
         msg: format!("The array index must be constant."),
         help: None,
     }
+
+    @formatted
+    array_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The array index `{index}` is out of bounds for an array of length {length}."),
+        help: Some("Negative indices count from the end of the array, so they must be at least `-length`.".to_string()),
+    }
+
+    @formatted
+    literal_cast_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The literal `{value}` cannot be cast to `{type_}`, since it is out of range."),
+        help: None,
+    }
+
+    @formatted
+    loop_bound_overflow {
+        args: (),
+        msg: format!("The loop bound overflows its type, so it cannot be evaluated."),
+        help: None,
+    }
 );
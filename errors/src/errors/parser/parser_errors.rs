@@ -298,4 +298,33 @@ create_messages!(
         msg: format!("An array {kind} must have at least one element."),
         help: None,
     }
+
+    @formatted
+    whole_number_too_large {
+        args: (found: impl Display),
+        msg: format!("expected a whole number of at most {} -- found '{found}'", usize::MAX),
+        help: None,
+    }
+
+    @formatted
+    struct_base_must_be_last {
+        args: (),
+        msg: format!("The base struct `..<expr>` must be the last item in a struct initializer."),
+        help: None,
+    }
+
+    /// When a hexadecimal field literal ends in a hex letter that runs into the `field` suffix.
+    @backtraced
+    lexer_hex_field_suffix_not_separated {
+        args: (input: impl Display),
+        msg: format!("The digits of the hexadecimal field literal `{input}` run into the `field` suffix."),
+        help: Some("Separate the digits from the suffix with an underscore, e.g., `0xff_field`.".to_string()),
+    }
+
+    @formatted
+    array_length_not_constant {
+        args: (length: impl Display),
+        msg: format!("The array length `{length}` is not known at compile time."),
+        help: Some("Use an integer literal, e.g. `[0u8; 4]`, or the name of a `u32` constant, e.g. `[0u8; N]`.".to_string()),
+    }
 );
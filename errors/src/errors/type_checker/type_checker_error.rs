@@ -712,4 +712,102 @@ create_messages!(
         msg: format!("An array cannot have a record as an element type"),
         help: None,
     }
+
+    @formatted
+    division_by_zero {
+        args: (),
+        msg: format!("Attempted to divide by a literal zero."),
+        help: Some("The divisor of `/`, `%`, `.div_wrapped()`, `.rem()`, `.rem_wrapped()` and `.mod()` must be nonzero.".to_string()),
+    }
+
+    @formatted
+    definition_used_as_value {
+        args: (kind: impl Display, name: impl Display),
+        msg: format!("The {kind} `{name}` is not a value and cannot be used in an expression."),
+        help: Some("Call a function as `name(..)`, and construct a struct or record as `Name { .. }`.".to_string()),
+    }
+
+    @formatted
+    return_value_without_return_type {
+        args: (function: impl Display),
+        msg: format!("The function `{function}` returns a value, but does not declare a return type."),
+        help: Some("Functions without a `-> <type>` annotation return `()`. Declare the type of the returned value.".to_string()),
+    }
+
+    @formatted
+    reserved_variable_name {
+        args: (name: impl Display),
+        msg: format!("`{name}` is a reserved keyword and cannot be used as a variable name."),
+        help: None,
+    }
+
+    @formatted
+    default_input_must_be_literal {
+        args: (input: impl Display),
+        msg: format!("The default value of the input `{input}` must be a literal."),
+        help: None,
+    }
+
+    @formatted
+    required_input_after_default {
+        args: (input: impl Display),
+        msg: format!("The input `{input}` has no default value, but follows an input with a default value."),
+        help: Some("Inputs with default values must come after all inputs without one.".to_string()),
+    }
+
+    @formatted
+    finalize_input_cannot_have_default {
+        args: (),
+        msg: format!("An input to a finalize block cannot have a default value."),
+        help: None,
+    }
+
+    @formatted
+    no_transitions {
+        args: (),
+        msg: format!("A program must have at least one transition function."),
+        help: Some("snarkVM rejects programs without transitions. Add one, e.g. `transition main() {}`.".to_string()),
+    }
+
+    @formatted
+    transition_input_cannot_have_default {
+        args: (),
+        msg: format!("An input to a transition cannot have a default value."),
+        help: Some("Transitions can be called from outside of Leo, where every input must be passed.".to_string()),
+    }
+
+    @formatted
+    array_length_not_constant {
+        args: (length: impl Display),
+        msg: format!("The array length `{length}` is not a `u32` constant in scope."),
+        help: Some("Use an integer literal, e.g. `[0u8; 4]`, or the name of a `u32` constant, e.g. `[0u8; N]`.".to_string()),
+    }
+
+    @formatted
+    empty_field_literal {
+        args: (),
+        msg: format!("A field literal must have at least one digit."),
+        help: None,
+    }
+
+    @formatted
+    malformed_field_literal {
+        args: (value: impl Display),
+        msg: format!("The field literal `{value}field` must only contain decimal digits and underscores."),
+        help: None,
+    }
+
+    @formatted
+    field_literal_out_of_modulus {
+        args: (value: impl Display),
+        msg: format!("The field literal `{value}field` is not less than the field modulus."),
+        help: Some("snarkVM would reduce it modulo the field modulus, so it would not have the written value.".to_string()),
+    }
+
+    @formatted
+    literal_cast_out_of_range {
+        args: (value: impl Display, type_: impl Display),
+        msg: format!("The literal `{value}` cannot be cast to `{type_}`, since it is out of range."),
+        help: None,
+    }
 );
@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt::Display;
+
+create_messages!(
+    /// TypeCheckerWarning enum that represents all the warnings for the type checker.
+    TypeCheckerWarning,
+    code_mask: 2000i32,
+    code_prefix: "TYC",
+
+    /// For when a variable or parameter is declared but never read.
+    @formatted
+    unused_variable {
+        args: (name: impl Display),
+        msg: format!("The variable `{name}` is never read."),
+        help: Some("Remove it, or use it in an expression.".to_string()),
+    }
+);
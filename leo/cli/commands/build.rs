@@ -41,7 +41,10 @@ use std::{
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                implicit_field_literals: options.enable_implicit_field_literals,
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
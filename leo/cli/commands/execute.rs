@@ -54,7 +54,7 @@ impl Command for Execute {
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
+        let inputs = match self.inputs.is_empty() {
             true => match input {
                 (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
                 _ => Vec::new(),
@@ -63,10 +63,7 @@ impl Command for Execute {
         };
 
         // Compose the `execute` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
-
-        // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+        let mut arguments = snarkvm_function_arguments(self.name, inputs);
 
         // Add the compiler options to the arguments.
         if self.compiler_options.offline {
@@ -115,6 +115,15 @@ pub trait Command {
     }
 }
 
+/// Returns the arguments of a snarkVM `run` or `execute` command that calls `function` with `inputs`.
+/// The arguments are parsed with `try_parse_from`, which treats the first argument as the binary name.
+/// So the function name is at index 1, and the function's `i`-th input is at index `i + 2`.
+pub(crate) fn snarkvm_function_arguments(function: String, inputs: Vec<String>) -> Vec<String> {
+    let mut arguments = vec![SNARKVM_COMMAND.to_string(), function];
+    arguments.extend(inputs);
+    arguments
+}
+
 /// Compiler Options wrapper for Build command. Also used by other commands which
 /// require Build command output as their input.
 #[derive(Parser, Clone, Debug, Default)]
@@ -133,6 +142,8 @@ pub struct BuildOptions {
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
     pub enable_dce: bool,
+    #[clap(long, help = "Parses integer literals without a type suffix as field literals.")]
+    pub enable_implicit_field_literals: bool,
     #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
     pub enable_all_ast_snapshots: bool,
     #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]
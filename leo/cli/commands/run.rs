@@ -46,7 +46,7 @@ impl Command for Run {
     fn apply(self, context: Context, input: Self::Input) -> Result<Self::Output> {
         // If input values are provided, then run the program with those inputs.
         // Otherwise, use the input file.
-        let mut inputs = match self.inputs.is_empty() {
+        let inputs = match self.inputs.is_empty() {
             true => match input {
                 (Some(input_ast), circuits) => input_ast.program_inputs(&self.name, circuits),
                 _ => Vec::new(),
@@ -55,10 +55,7 @@ impl Command for Run {
         };
 
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
-
-        // Add the program inputs to the arguments.
-        arguments.append(&mut inputs);
+        let arguments = snarkvm_function_arguments(self.name, inputs);
 
         // Open the Leo build/ directory
         let path = context.dir()?;
@@ -44,6 +44,19 @@ pub fn format_event() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn snarkvm_function_arguments_skip_binary_name() {
+    use clap::Parser;
+    use snarkvm::cli::Run as SnarkVMRun;
+
+    let arguments = crate::cli::snarkvm_function_arguments("main".to_string(), vec!["1u32".into(), "2field".into()]);
+    assert_eq!(arguments, ["snarkvm", "main", "1u32", "2field"]);
+
+    // snarkVM must see the first user-supplied input as the function's first input, not the binary or function name.
+    let run = SnarkVMRun::try_parse_from(&arguments).unwrap();
+    assert_eq!(format!("{run:?}"), "Run { function: main, inputs: [1u32, 2field] }");
+}
+
 // todo (collin): uncomment after refactor
 // #[test]
 // pub fn build_pedersen_hash() -> Result<()> {